@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// The checked-in store of accepted answers
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.json");
+
+// An accepted answer. Inputs are identified by a hash of their text, so answers for anybody's
+// input can live side by side.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: String,
+}
+
+#[derive(Default, Eq, PartialEq, Debug)]
+pub struct AnswerStore {
+    answers: Vec<Answer>,
+}
+
+#[derive(Eq, PartialEq, Debug)]
+pub enum Verdict {
+    Correct,
+    Mismatch { expected: String },
+    Unrecorded,
+}
+
+impl AnswerStore {
+    // A missing file is an empty store
+    pub fn load(path: &Path) -> io::Result<AnswerStore> {
+        match fs::read_to_string(path) {
+            Ok(json) => Ok(AnswerStore {
+                answers: serde_json::from_str(&json).map_err(io::Error::other)?,
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(AnswerStore::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.answers).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_hash: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.input_hash == input_hash)
+            .map(|a| a.answer.as_str())
+    }
+
+    pub fn verify(&self, day: u8, part: u8, input_hash: &str, answer: &str) -> Verdict {
+        match self.get(day, part, input_hash) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unrecorded,
+        }
+    }
+
+    // Accepted answers never change, so this only adds answers that aren't recorded yet. Returns
+    // whether it was added.
+    pub fn record(&mut self, answer: Answer) -> bool {
+        if self
+            .get(answer.day, answer.part, &answer.input_hash)
+            .is_some()
+        {
+            return false;
+        }
+
+        self.answers.push(answer);
+        self.answers.sort();
+        true
+    }
+}
+
+// 64-bit FNV-1a, as hex
+pub fn input_hash(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(day: u8, part: u8, input_hash: &str, answer: &str) -> Answer {
+        Answer {
+            day,
+            part,
+            input_hash: input_hash.to_string(),
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("1abc2\n"), input_hash("1abc2\n\n"));
+    }
+
+    #[test]
+    fn test_answer_store() {
+        let mut store = AnswerStore::default();
+        assert!(store.record(answer(3, 1, "abc", "528231")));
+        assert!(!store.record(answer(3, 1, "abc", "529618")));
+        assert!(store.record(answer(1, 2, "abc", "281")));
+
+        assert_eq!(store.verify(3, 1, "abc", "528231"), Verdict::Correct);
+        assert_eq!(
+            store.verify(3, 1, "abc", "529618"),
+            Verdict::Mismatch {
+                expected: "528231".to_string()
+            }
+        );
+        assert_eq!(store.verify(3, 1, "def", "1"), Verdict::Unrecorded);
+        assert_eq!(store.answers[0].day, 1);
+    }
+
+    #[test]
+    fn test_answer_store_round_trip() {
+        let mut store = AnswerStore::default();
+        store.record(answer(13, 2, "abc", "32069"));
+
+        let path = std::env::temp_dir().join(format!("aoc_answers_{}.json", std::process::id()));
+        store.save(&path).unwrap();
+        assert_eq!(AnswerStore::load(&path).unwrap(), store);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(AnswerStore::load(&path).unwrap(), AnswerStore::default());
+    }
+}
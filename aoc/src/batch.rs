@@ -0,0 +1,190 @@
+use crate::bench;
+use crate::report::RunResult;
+use aoc_core::checked;
+use aoc_core::{DynSolution, Input, Part};
+use rayon::prelude::*;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// The files in `dir`, by name. Hidden files (like .DS_Store) and subdirectories are skipped.
+pub fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if !hidden && entry.file_type()?.is_file() {
+            files.push(entry.path());
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+// Solves `parts` for every file, a file per thread, in file then part order. Errors and panics are
+// results like any other, so one bad input doesn't stop the rest.
+pub fn run(solution: &dyn DynSolution, parts: &[Part], files: &[PathBuf]) -> Vec<RunResult> {
+    files
+        .par_iter()
+        .flat_map_iter(|path| run_file(solution, parts, path))
+        .collect()
+}
+
+fn run_file(solution: &dyn DynSolution, parts: &[Part], path: &Path) -> Vec<RunResult> {
+    let input = Input::File(path.to_path_buf());
+    let name = file_name(path);
+    let mut results = vec![];
+
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) => {
+            let result = RunResult::new(solution.day(), parts[0], name);
+            return vec![result.failed(format!("Couldn't read input {}: {}", input, err))];
+        }
+    };
+
+    if let Some(warning) = aoc_core::normalize(&text).warning(&name) {
+        eprintln!("warning: {}", warning);
+    }
+
+    for &part in parts {
+        let result = RunResult::new(solution.day(), part, name.clone());
+        match checked::catch(|| solution.run_timed(&text, part)) {
+            Ok(Ok(timed)) => results.push(result.answered(timed.answer, timed.parse + timed.solve)),
+            Ok(Err(err)) => {
+                // Every part parses the same input, so they'd all fail the same way
+                results.push(result.failed(err.with_input(&input).to_string()));
+                break;
+            }
+            Err(panic) => results.push(result.failed(panic.to_string())),
+        }
+    }
+
+    results
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+// A row per file and part, then the errors in full
+pub fn table(results: &[RunResult]) -> String {
+    let width = |f: fn(&RunResult) -> usize, header: &str| {
+        results.iter().map(f).max().unwrap_or(0).max(header.len())
+    };
+    let input_width = width(|r| r.input.len(), "input");
+    let answer_width = width(|r| r.answer.as_ref().map_or(5, String::len), "answer");
+
+    let mut table = format!(
+        "{:<input_width$}  part  {:<answer_width$}  {:>10}\n",
+        "input", "answer", "time"
+    );
+    for result in results {
+        let (answer, time) = match &result.answer {
+            Some(answer) => (answer.as_str(), bench::format_ns(result.elapsed_ns)),
+            None => ("ERROR", String::new()),
+        };
+        table += &format!(
+            "{:<input_width$}  {:>4}  {:<answer_width$}  {:>10}\n",
+            result.input, result.part, answer, time
+        );
+    }
+
+    let failures = results.iter().filter_map(|r| Some((r, r.error.as_ref()?)));
+    for (i, (result, error)) in failures.enumerate() {
+        if i == 0 {
+            table += "\nerrors:\n";
+        }
+        table += &format!("{} part {}: {}\n", result.input, result.part, error);
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{Fixtures, ParseError, Solution};
+    use std::env;
+
+    // Sums its numbers in part 1, and panics on 13 in part 2
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 99;
+        const FIXTURES: &'static Fixtures = &Fixtures {
+            real: "",
+            tests: &[],
+            answers: &[],
+        };
+
+        type Parsed = Vec<u32>;
+        type Answer = u32;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            input
+                .lines()
+                .map(|line| {
+                    line.parse()
+                        .map_err(|_| ParseError::at(line, line, "a number"))
+                })
+                .collect()
+        }
+
+        fn part_1(parsed: Vec<u32>) -> u32 {
+            parsed.iter().sum()
+        }
+
+        fn part_2(parsed: Vec<u32>) -> u32 {
+            assert!(!parsed.contains(&13), "unlucky");
+            parsed.len() as u32
+        }
+    }
+
+    #[test]
+    fn test_batch() {
+        let dir = env::temp_dir().join(format!("aoc_batch_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("bob.txt"), "1\n13\n").unwrap();
+        fs::write(dir.join("alice.txt"), "1\n2\n3\n").unwrap();
+        fs::write(dir.join("carol.txt"), "1\nx\n").unwrap();
+        fs::write(dir.join(".hidden"), "").unwrap();
+
+        let files = input_files(&dir).unwrap();
+        let names = files.iter().map(|f| file_name(f)).collect::<Vec<_>>();
+        assert_eq!(names, ["alice.txt", "bob.txt", "carol.txt"]);
+
+        let results = run(&Sum, &[Part::One, Part::Two], &files);
+        let summary = results
+            .iter()
+            .map(|r| (r.input.as_str(), r.part, r.answer.as_deref()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                ("alice.txt", 1, Some("6")),
+                ("alice.txt", 2, Some("3")),
+                ("bob.txt", 1, Some("14")),
+                ("bob.txt", 2, None),
+                ("carol.txt", 1, None),
+            ]
+        );
+
+        let panic = results[3].error.as_ref().unwrap();
+        assert!(panic.starts_with("panic at aoc/src/batch.rs:"), "{}", panic);
+        assert!(panic.ends_with(": unlucky"), "{}", panic);
+        assert!(results[4].error.as_ref().unwrap().contains("carol.txt"));
+
+        let table = table(&results);
+        assert!(table.starts_with("input      part  answer        time\n"));
+        assert!(table.contains("\nbob.txt       2  ERROR             \n"));
+        assert!(table.contains("\nerrors:\nbob.txt part 2: panic at "));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
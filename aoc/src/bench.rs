@@ -0,0 +1,261 @@
+use aoc_core::{DynSolution, ParseError, Part};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// Every `bench` of a real input appends its results here, a JSON object per line, for the
+// dashboard's sparklines. It's gitignored.
+pub const HISTORY_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../.aoc_bench_history");
+
+#[derive(Serialize, Deserialize, Default, PartialEq, Debug)]
+pub struct Baseline {
+    pub results: Vec<BenchResult>,
+}
+
+// Timing statistics for one thing that was measured: a day's parse, or one of its parts
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct BenchResult {
+    pub day: u8,
+    pub target: String,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub stddev_ns: u64,
+}
+
+pub struct BenchOptions {
+    pub max_runs: usize,
+    pub max_time: Duration,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> io::Result<Baseline> {
+        let json = fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(io::Error::other)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    pub fn find(&self, day: u8, target: &str) -> Option<&BenchResult> {
+        self.results
+            .iter()
+            .find(|r| r.day == day && r.target == target)
+    }
+}
+
+impl BenchResult {
+    pub fn from_samples(day: u8, target: &str, samples: &[Duration]) -> BenchResult {
+        let mut ns = samples
+            .iter()
+            .map(|d| d.as_nanos() as u64)
+            .collect::<Vec<_>>();
+        ns.sort_unstable();
+
+        let mid = ns.len() / 2;
+        let median_ns = if ns.len() % 2 == 0 {
+            (ns[mid - 1] + ns[mid]) / 2
+        } else {
+            ns[mid]
+        };
+
+        let mean = ns.iter().sum::<u64>() as f64 / ns.len() as f64;
+        let variance = ns.iter().map(|n| (*n as f64 - mean).powi(2)).sum::<f64>() / ns.len() as f64;
+
+        BenchResult {
+            day,
+            target: target.to_string(),
+            runs: ns.len(),
+            min_ns: ns[0],
+            median_ns,
+            stddev_ns: variance.sqrt() as u64,
+        }
+    }
+
+    // The relative change in median time compared to a previous result: 0.1 means 10% slower
+    pub fn change_from(&self, previous: &BenchResult) -> f64 {
+        (self.median_ns as f64 - previous.median_ns as f64) / previous.median_ns.max(1) as f64
+    }
+}
+
+// Runs each part repeatedly (re-parsing every time, since the parts consume their input) until
+// either `max_runs` runs or `max_time` has passed. Every part gets at least one run.
+pub fn bench(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+    options: &BenchOptions,
+) -> Result<Vec<BenchResult>, ParseError> {
+    let mut parse_samples = vec![];
+    let mut part_results = vec![];
+
+    for part in parts {
+        let mut samples = vec![];
+        let start = Instant::now();
+
+        while samples.is_empty()
+            || (samples.len() < options.max_runs && start.elapsed() < options.max_time)
+        {
+            let timed = solution.run_timed(input, *part)?;
+            parse_samples.push(timed.parse);
+            samples.push(timed.solve);
+        }
+
+        let target = format!("part {}", part);
+        part_results.push(BenchResult::from_samples(solution.day(), &target, &samples));
+    }
+
+    let mut results = vec![BenchResult::from_samples(
+        solution.day(),
+        "parse",
+        &parse_samples,
+    )];
+    results.append(&mut part_results);
+    Ok(results)
+}
+
+pub fn append_history(path: &Path, results: &[BenchResult]) -> io::Result<()> {
+    let mut lines = String::new();
+    for result in results {
+        lines += &serde_json::to_string(result).map_err(io::Error::other)?;
+        lines += "\n";
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(lines.as_bytes())
+}
+
+// Oldest first. A missing file is no history, and lines that don't parse are skipped.
+pub fn load_history(path: &Path) -> io::Result<Vec<BenchResult>> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(text
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(err) => Err(err),
+    }
+}
+
+// A day's total median time (parse plus its parts) for each time all of `parts` were benched,
+// oldest first. Each bench of a day writes its parse result first, so that's where a new one
+// starts. Benches of a single part are left out, since their totals aren't comparable.
+pub fn day_totals(history: &[BenchResult], day: u8, parts: &[Part]) -> Vec<u64> {
+    let mut benches: Vec<Vec<&BenchResult>> = vec![];
+    for result in history.iter().filter(|r| r.day == day) {
+        if result.target == "parse" || benches.is_empty() {
+            benches.push(vec![]);
+        }
+        benches.last_mut().unwrap().push(result);
+    }
+
+    let every_part = |bench: &Vec<&BenchResult>| {
+        parts.iter().all(|part| {
+            let target = format!("part {}", part);
+            bench.iter().any(|r| r.target == target)
+        })
+    };
+    benches
+        .iter()
+        .filter(|bench| every_part(bench))
+        .map(|bench| bench.iter().map(|r| r.median_ns).sum())
+        .collect()
+}
+
+pub fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn ms(samples: &[u64]) -> Vec<Duration> {
+        samples
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect()
+    }
+
+    #[test]
+    fn test_bench_result_from_samples() {
+        let result = BenchResult::from_samples(5, "part 1", &ms(&[4, 2, 8, 6]));
+        assert_eq!(result.runs, 4);
+        assert_eq!(result.min_ns, 2_000_000);
+        assert_eq!(result.median_ns, 5_000_000);
+        assert_eq!(result.stddev_ns, 2_236_067);
+
+        let result = BenchResult::from_samples(5, "part 1", &ms(&[3, 1, 2]));
+        assert_eq!(result.median_ns, 2_000_000);
+    }
+
+    #[test]
+    fn test_bench_result_change_from() {
+        let old = BenchResult::from_samples(5, "part 1", &ms(&[10]));
+        let new = BenchResult::from_samples(5, "part 1", &ms(&[12]));
+        assert!((new.change_from(&old) - 0.2).abs() < 1e-9);
+        assert!((old.change_from(&new) + 1.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let baseline = Baseline {
+            results: vec![BenchResult::from_samples(12, "parse", &ms(&[1, 2]))],
+        };
+        let path = std::env::temp_dir().join(format!("aoc_bench_{}.json", std::process::id()));
+        baseline.save(&path).unwrap();
+        assert_eq!(Baseline::load(&path).unwrap(), baseline);
+        assert!(baseline.find(12, "parse").is_some());
+        assert!(baseline.find(12, "part 1").is_none());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_history() {
+        let path = env::temp_dir().join(format!("aoc_bench_history_test_{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(load_history(&path).unwrap(), vec![]);
+
+        let result = |day, target: &str, median_ns| BenchResult {
+            day,
+            target: target.to_string(),
+            runs: 1,
+            min_ns: median_ns,
+            median_ns,
+            stddev_ns: 0,
+        };
+        let first = [
+            result(5, "parse", 10),
+            result(5, "part 1", 20),
+            result(5, "part 2", 30),
+        ];
+        let second = [
+            result(6, "parse", 1),
+            result(5, "parse", 5),
+            result(5, "part 2", 7),
+            result(5, "parse", 4),
+            result(5, "part 1", 8),
+            result(5, "part 2", 6),
+        ];
+        append_history(&path, &first).unwrap();
+        append_history(&path, &second).unwrap();
+
+        let history = load_history(&path).unwrap();
+        assert_eq!(history.len(), 9);
+        assert_eq!(history[..3], first);
+        // `bench 5 2` only benched part 2, so it doesn't count
+        let both = [Part::One, Part::Two];
+        assert_eq!(day_totals(&history, 5, &both), [60, 18]);
+        assert_eq!(day_totals(&history, 5, &[Part::Two]), [60, 12, 18]);
+        assert_eq!(day_totals(&history, 6, &[]), [1]);
+        assert_eq!(day_totals(&history, 6, &both), [] as [u64; 0]);
+        assert_eq!(day_totals(&history, 7, &both), [] as [u64; 0]);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
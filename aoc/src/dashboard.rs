@@ -0,0 +1,638 @@
+use crate::answers::{self, AnswerStore, Verdict};
+use crate::bench;
+use crate::registry;
+use aoc_core::checked;
+use aoc_core::{DynSolution, Input, Part};
+use ratatui::crossterm::event::{self, Event as TermEvent, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+use std::io;
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+// How many of the most recent benches a day's sparkline shows
+const HISTORY_LEN: usize = 16;
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Status {
+    Pending,
+    Running,
+    Passed,
+    Failed,
+}
+
+impl Status {
+    fn style(&self) -> Style {
+        let color = match self {
+            Status::Pending => Color::DarkGray,
+            Status::Running => Color::Yellow,
+            Status::Passed => Color::Green,
+            Status::Failed => Color::Red,
+        };
+        Style::default().fg(color)
+    }
+}
+
+// Something for the worker to do
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Job {
+    Part(usize, Part),
+    Tests(usize),
+}
+
+// What the worker reports back, by index into the dashboard's days
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Event {
+    Started(Job),
+    Part(usize, Part, Result<(String, Duration), String>, Status),
+    Tests(usize, Vec<TestResult>),
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct TestResult {
+    pub label: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+#[derive(Clone, Debug)]
+struct PartState {
+    part: Part,
+    status: Status,
+    // The answer and how long it took, or the error
+    outcome: Option<Result<(String, Duration), String>>,
+}
+
+struct DayState {
+    solution: &'static dyn DynSolution,
+    parts: Vec<PartState>,
+    tests: Option<Vec<TestResult>>,
+    tests_running: bool,
+    history: Vec<u64>,
+}
+
+impl DayState {
+    fn status(&self) -> Status {
+        let statuses = self.parts.iter().map(|p| p.status).collect::<Vec<_>>();
+        let tests_failed = self.tests.iter().flatten().any(|t| !t.passed);
+
+        if self.tests_running || statuses.contains(&Status::Running) {
+            Status::Running
+        } else if tests_failed || statuses.contains(&Status::Failed) {
+            Status::Failed
+        } else if statuses.iter().all(|s| *s == Status::Passed) {
+            Status::Passed
+        } else {
+            Status::Pending
+        }
+    }
+
+    fn elapsed(&self) -> Option<Duration> {
+        self.parts
+            .iter()
+            .filter_map(|p| {
+                p.outcome
+                    .as_ref()?
+                    .as_ref()
+                    .ok()
+                    .map(|(_, elapsed)| *elapsed)
+            })
+            .reduce(|a, b| a + b)
+    }
+}
+
+// Every registered day, what's known about it so far, and which one is selected
+pub struct Dashboard {
+    days: Vec<DayState>,
+    table: TableState,
+}
+
+impl Dashboard {
+    pub fn new(
+        solutions: &[&'static dyn DynSolution],
+        history: &[bench::BenchResult],
+    ) -> Dashboard {
+        let days = solutions
+            .iter()
+            .map(|solution| DayState {
+                solution: *solution,
+                parts: solution
+                    .parts()
+                    .iter()
+                    .map(|part| PartState {
+                        part: *part,
+                        status: Status::Pending,
+                        outcome: None,
+                    })
+                    .collect(),
+                tests: None,
+                tests_running: false,
+                history: bench::day_totals(history, solution.day(), solution.parts()),
+            })
+            .collect();
+
+        Dashboard {
+            days,
+            table: TableState::default().with_selected(Some(0)),
+        }
+    }
+
+    fn selected(&self) -> usize {
+        self.table.selected().unwrap_or(0)
+    }
+
+    // Everything for a day: its tests, then each part
+    fn day_jobs(&self, idx: usize) -> Vec<Job> {
+        let parts = self.days[idx].parts.iter().map(|p| Job::Part(idx, p.part));
+        [Job::Tests(idx)].into_iter().chain(parts).collect()
+    }
+
+    pub fn all_jobs(&self) -> Vec<Job> {
+        (0..self.days.len())
+            .flat_map(|idx| self.day_jobs(idx))
+            .collect()
+    }
+
+    // The jobs a key asks for, or None to quit
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<Vec<Job>> {
+        let selected = self.selected();
+        let jobs = match key {
+            KeyCode::Char('q') | KeyCode::Esc => return None,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.table.select(Some(selected.saturating_sub(1)));
+                vec![]
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.table
+                    .select(Some((selected + 1).min(self.days.len() - 1)));
+                vec![]
+            }
+            KeyCode::Enter | KeyCode::Char('r') => self.day_jobs(selected),
+            KeyCode::Char('a') => self.all_jobs(),
+            KeyCode::Char(c) => {
+                let part = c.to_string().parse::<Part>().ok();
+                let day = &self.days[selected];
+                match part.filter(|part| day.parts.iter().any(|p| p.part == *part)) {
+                    Some(part) => vec![Job::Part(selected, part)],
+                    None => vec![],
+                }
+            }
+            _ => vec![],
+        };
+        Some(jobs)
+    }
+
+    pub fn apply(&mut self, event: Event) {
+        match event {
+            Event::Started(Job::Tests(idx)) => self.days[idx].tests_running = true,
+            Event::Started(Job::Part(idx, part)) => {
+                self.part_mut(idx, part).status = Status::Running
+            }
+            Event::Part(idx, part, outcome, status) => {
+                let state = self.part_mut(idx, part);
+                state.status = status;
+                state.outcome = Some(outcome);
+            }
+            Event::Tests(idx, tests) => {
+                let day = &mut self.days[idx];
+                day.tests_running = false;
+                day.tests = Some(tests);
+            }
+        }
+    }
+
+    fn part_mut(&mut self, idx: usize, part: Part) -> &mut PartState {
+        self.days[idx]
+            .parts
+            .iter_mut()
+            .find(|p| p.part == part)
+            .unwrap()
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame) {
+        let [table_area, detail_area, help_area] = Layout::vertical([
+            Constraint::Min(5),
+            Constraint::Length(10),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let header = Row::new(["day", "status", "part 1", "part 2", "time", "bench history"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        let rows = self.days.iter().map(|day| {
+            let status = day.status();
+            let answer = |part: Part| match day.parts.iter().find(|p| p.part == part) {
+                Some(PartState {
+                    outcome: Some(Ok((answer, _))),
+                    ..
+                }) => answer.clone(),
+                Some(PartState {
+                    outcome: Some(Err(_)),
+                    ..
+                }) => "ERROR".to_string(),
+                Some(_) => String::new(),
+                None => "-".to_string(),
+            };
+
+            Row::new([
+                Cell::from(format!("{:02}", day.solution.day())),
+                Cell::from(format!("{:?}", status).to_lowercase()).style(status.style()),
+                Cell::from(answer(Part::One)),
+                Cell::from(answer(Part::Two)),
+                Cell::from(
+                    day.elapsed()
+                        .map_or(String::new(), |e| format!("{:.2?}", e)),
+                ),
+                Cell::from(sparkline(&day.history, HISTORY_LEN)),
+            ])
+        });
+
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Length(8),
+            Constraint::Length(16),
+            Constraint::Length(16),
+            Constraint::Length(10),
+            Constraint::Length(HISTORY_LEN as u16),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::bordered().title(" Advent of Code 2023 "))
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(table, table_area, &mut self.table);
+
+        let day = &self.days[self.selected()];
+        let detail = Paragraph::new(detail_lines(day))
+            .block(Block::bordered().title(format!(" Day {:02} ", day.solution.day())));
+        frame.render_widget(detail, detail_area);
+
+        let help = "↑/↓ select   enter run day   1/2 rerun part   a run all   q quit";
+        frame.render_widget(
+            Line::from(help).style(Style::default().fg(Color::DarkGray)),
+            help_area,
+        );
+    }
+}
+
+fn detail_lines(day: &DayState) -> Vec<Line<'static>> {
+    let mut lines = vec![];
+
+    for part in &day.parts {
+        let text = match &part.outcome {
+            Some(Ok((answer, elapsed))) => format!("{} ({:.2?})", answer, elapsed),
+            Some(Err(err)) => err.lines().next().unwrap_or_default().to_string(),
+            None => String::new(),
+        };
+        let line = format!(
+            "part {}: {:<8} {}",
+            part.part,
+            format!("{:?}", part.status).to_lowercase(),
+            text
+        );
+        lines.push(Line::from(line).style(part.status.style()));
+    }
+
+    match &day.tests {
+        _ if day.tests_running => {
+            lines.push(Line::from("tests: running").style(Status::Running.style()))
+        }
+        Some(tests) if tests.is_empty() => lines.push(Line::from("tests: none")),
+        Some(tests) => {
+            for test in tests {
+                let status = if test.passed {
+                    Status::Passed
+                } else {
+                    Status::Failed
+                };
+                let line = format!("{}: {}", test.label, test.detail);
+                lines.push(Line::from(line).style(status.style()));
+            }
+        }
+        None => lines.push(Line::from("tests: pending").style(Status::Pending.style())),
+    }
+
+    lines
+}
+
+// The last `len` values as block characters, scaled between their min and max
+pub fn sparkline(values: &[u64], len: usize) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let values = &values[values.len().saturating_sub(len)..];
+    let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+
+    values
+        .iter()
+        .map(|value| {
+            let scaled = (value - min) as f64 / (max - min).max(1) as f64;
+            BARS[(scaled * (BARS.len() - 1) as f64).round() as usize]
+        })
+        .collect()
+}
+
+// Runs jobs one at a time, reporting as it goes
+fn run_jobs(
+    solutions: Vec<&'static dyn DynSolution>,
+    store: AnswerStore,
+    jobs: Receiver<Job>,
+    events: Sender<Event>,
+) {
+    for job in jobs {
+        if events.send(Event::Started(job)).is_err() {
+            return;
+        }
+
+        let event = match job {
+            Job::Part(idx, part) => {
+                let (outcome, status) = run_part(solutions[idx], &store, part);
+                Event::Part(idx, part, outcome, status)
+            }
+            Job::Tests(idx) => Event::Tests(idx, run_tests(solutions[idx])),
+        };
+        if events.send(event).is_err() {
+            return;
+        }
+    }
+}
+
+// The real input's answer, which fails if it's not the accepted one
+fn run_part(
+    solution: &dyn DynSolution,
+    store: &AnswerStore,
+    part: Part,
+) -> (Result<(String, Duration), String>, Status) {
+    let text = match solution.fixtures().text(&Input::Real) {
+        Ok(text) => text,
+        Err(err) => return (Err(format!("Couldn't read input: {}", err)), Status::Failed),
+    };
+
+    match checked::catch(|| solution.run_timed(&text, part)) {
+        Ok(Ok(timed)) => {
+            let elapsed = timed.parse + timed.solve;
+            let input_hash = answers::input_hash(&text);
+            match store.verify(solution.day(), part.number(), &input_hash, &timed.answer) {
+                Verdict::Mismatch { expected } => (
+                    Err(format!(
+                        "got {}, but {} was accepted",
+                        timed.answer, expected
+                    )),
+                    Status::Failed,
+                ),
+                _ => (Ok((timed.answer, elapsed)), Status::Passed),
+            }
+        }
+        Ok(Err(err)) => (
+            Err(err.with_input(&Input::Real).to_string()),
+            Status::Failed,
+        ),
+        Err(panic) => (Err(panic.to_string()), Status::Failed),
+    }
+}
+
+// The fixture answers from answers.toml
+fn run_tests(solution: &dyn DynSolution) -> Vec<TestResult> {
+    let fixtures = solution.fixtures();
+    fixtures
+        .answers
+        .iter()
+        .map(|(n, part, expected)| {
+            let input = Input::Test(*n);
+            let label = format!("{} part {}", input, part);
+            let answer = fixtures
+                .text(&input)
+                .map_err(|err| err.to_string())
+                .and_then(|text| match checked::catch(|| solution.run(&text, *part)) {
+                    Ok(Ok(answer)) => Ok(answer),
+                    Ok(Err(err)) => Err(err.to_string()),
+                    Err(panic) => Err(panic.to_string()),
+                });
+
+            match answer {
+                Ok(answer) if answer == *expected => TestResult {
+                    label,
+                    passed: true,
+                    detail: "ok".to_string(),
+                },
+                Ok(answer) => TestResult {
+                    label,
+                    passed: false,
+                    detail: format!("MISMATCH (got {}, expected {})", answer, expected),
+                },
+                Err(err) => TestResult {
+                    label,
+                    passed: false,
+                    detail: format!("ERROR {}", err.lines().next().unwrap_or_default()),
+                },
+            }
+        })
+        .collect()
+}
+
+pub fn dashboard(answers_path: &Path) -> io::Result<()> {
+    let solutions = registry::DAYS.to_vec();
+    let store = AnswerStore::load(answers_path)?;
+    let history = bench::load_history(Path::new(bench::HISTORY_PATH))?;
+    let mut dashboard = Dashboard::new(&solutions, &history);
+
+    let (job_tx, job_rx) = mpsc::channel();
+    let (event_tx, event_rx) = mpsc::channel();
+    thread::spawn(move || run_jobs(solutions, store, job_rx, event_tx));
+    for job in dashboard.all_jobs() {
+        job_tx.send(job).unwrap();
+    }
+
+    ratatui::run(|terminal| event_loop(terminal, &mut dashboard, &job_tx, &event_rx))
+}
+
+fn event_loop(
+    terminal: &mut DefaultTerminal,
+    dashboard: &mut Dashboard,
+    jobs: &Sender<Job>,
+    events: &Receiver<Event>,
+) -> io::Result<()> {
+    loop {
+        for event in events.try_iter() {
+            dashboard.apply(event);
+        }
+        terminal.draw(|frame| dashboard.draw(frame))?;
+
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+        if let TermEvent::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match dashboard.handle_key(key.code) {
+                Some(new_jobs) => new_jobs.into_iter().for_each(|job| {
+                    let _ = jobs.send(job);
+                }),
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn screen(dashboard: &mut Dashboard) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(90, 24)).unwrap();
+        terminal.draw(|frame| dashboard.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn dashboard() -> Dashboard {
+        let result = |target: &str, ms: u64| bench::BenchResult {
+            day: 1,
+            target: target.to_string(),
+            runs: 1,
+            min_ns: ms * 1_000_000,
+            median_ns: ms * 1_000_000,
+            stddev_ns: 0,
+        };
+        // Full benches totalling 8, 10 and 9 ms, and a part 2 bench on its own that isn't plotted
+        let history = vec![
+            result("parse", 5),
+            result("part 1", 1),
+            result("part 2", 2),
+            result("parse", 1),
+            result("part 2", 40),
+            result("parse", 7),
+            result("part 1", 1),
+            result("part 2", 2),
+            result("parse", 6),
+            result("part 1", 1),
+            result("part 2", 2),
+        ];
+        Dashboard::new(&registry::DAYS[..2], &history)
+    }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[], 4), "");
+        assert_eq!(sparkline(&[3, 3], 4), "▁▁");
+        assert_eq!(sparkline(&[0, 7, 14], 4), "▁▅█");
+        assert_eq!(sparkline(&[100, 0, 7, 14], 3), "▁▅█");
+    }
+
+    #[test]
+    fn test_keys() {
+        let mut dashboard = dashboard();
+        assert_eq!(dashboard.all_jobs().len(), 6);
+
+        assert_eq!(
+            dashboard.handle_key(KeyCode::Char('2')),
+            Some(vec![Job::Part(0, Part::Two)])
+        );
+        assert_eq!(dashboard.handle_key(KeyCode::Down), Some(vec![]));
+        assert_eq!(dashboard.handle_key(KeyCode::Down), Some(vec![]));
+        assert_eq!(dashboard.selected(), 1);
+        assert_eq!(
+            dashboard.handle_key(KeyCode::Enter),
+            Some(vec![
+                Job::Tests(1),
+                Job::Part(1, Part::One),
+                Job::Part(1, Part::Two)
+            ])
+        );
+        assert_eq!(dashboard.handle_key(KeyCode::Char('3')), Some(vec![]));
+        assert_eq!(dashboard.handle_key(KeyCode::Up), Some(vec![]));
+        assert_eq!(dashboard.selected(), 0);
+        assert_eq!(dashboard.handle_key(KeyCode::Char('q')), None);
+    }
+
+    #[test]
+    fn test_status() {
+        let mut dashboard = dashboard();
+        assert_eq!(dashboard.days[0].status(), Status::Pending);
+
+        dashboard.apply(Event::Started(Job::Tests(0)));
+        assert_eq!(dashboard.days[0].status(), Status::Running);
+        let test = TestResult {
+            label: "test_1.txt part 1".to_string(),
+            passed: true,
+            detail: "ok".to_string(),
+        };
+        dashboard.apply(Event::Tests(0, vec![test]));
+        assert_eq!(dashboard.days[0].status(), Status::Pending);
+
+        let answer = |answer: &str| Ok((answer.to_string(), Duration::from_millis(1)));
+        dashboard.apply(Event::Part(0, Part::One, answer("142"), Status::Passed));
+        dashboard.apply(Event::Started(Job::Part(0, Part::Two)));
+        assert_eq!(dashboard.days[0].status(), Status::Running);
+        dashboard.apply(Event::Part(0, Part::Two, answer("281"), Status::Passed));
+        assert_eq!(dashboard.days[0].status(), Status::Passed);
+        assert_eq!(dashboard.days[0].elapsed(), Some(Duration::from_millis(2)));
+
+        dashboard.apply(Event::Part(
+            0,
+            Part::Two,
+            Err("panic".to_string()),
+            Status::Failed,
+        ));
+        assert_eq!(dashboard.days[0].status(), Status::Failed);
+    }
+
+    #[test]
+    fn test_draw() {
+        let mut dashboard = dashboard();
+        dashboard.apply(Event::Part(
+            0,
+            Part::One,
+            Ok(("54304".to_string(), Duration::from_micros(150))),
+            Status::Passed,
+        ));
+        dashboard.apply(Event::Tests(0, run_tests(registry::DAYS[0])));
+
+        let screen = screen(&mut dashboard);
+        assert!(screen.contains("01  pending  54304"), "{}", screen);
+        assert!(screen.contains("150.00µs"), "{}", screen);
+        assert!(screen.contains("▁█▅"), "{}", screen);
+        assert!(screen.contains("02  pending"), "{}", screen);
+        assert!(screen.contains(" Day 01 "), "{}", screen);
+        assert!(
+            screen.contains("part 1: passed   54304 (150.00µs)"),
+            "{}",
+            screen
+        );
+        assert!(screen.contains("test_1.txt part 1: ok"), "{}", screen);
+    }
+
+    #[test]
+    fn test_run_jobs() {
+        let (job_tx, job_rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        let solutions = registry::DAYS[..1].to_vec();
+        job_tx.send(Job::Tests(0)).unwrap();
+        job_tx.send(Job::Part(0, Part::One)).unwrap();
+        drop(job_tx);
+
+        run_jobs(solutions, AnswerStore::default(), job_rx, event_tx);
+        let events = event_rx.iter().collect::<Vec<_>>();
+
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[0], Event::Started(Job::Tests(0)));
+        assert!(matches!(&events[1], Event::Tests(0, tests) if tests.iter().all(|t| t.passed)));
+        assert_eq!(events[2], Event::Started(Job::Part(0, Part::One)));
+        assert!(matches!(
+            &events[3],
+            Event::Part(0, Part::One, Ok(_), Status::Passed)
+        ));
+    }
+}
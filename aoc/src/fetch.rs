@@ -0,0 +1,151 @@
+use crate::answers;
+use crate::site::{Site, YEAR};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Downloaded inputs, which are never downloaded again. It's gitignored.
+pub const DEFAULT_CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../.aoc_cache");
+
+#[derive(Debug)]
+pub struct Fetched {
+    pub path: PathBuf,
+    pub text: String,
+    pub downloaded: bool,
+}
+
+// `{cache}/2023/day_NN/{user}.txt`. Everybody's input is different, so the user is part of the key:
+// a hash of their session token, which keeps the token itself off disk.
+pub fn cache_path(cache_dir: &Path, day: u8, session: &str) -> PathBuf {
+    cache_dir
+        .join(YEAR.to_string())
+        .join(format!("day_{:02}", day))
+        .join(format!("{}.txt", answers::input_hash(session)))
+}
+
+// The cached input, or else the downloaded one
+pub fn fetch(cache_dir: &Path, base_url: &str, session: &str, day: u8) -> Result<Fetched, String> {
+    let path = cache_path(cache_dir, day, session);
+    if let Ok(text) = fs::read_to_string(&path) {
+        return Ok(Fetched {
+            path,
+            text,
+            downloaded: false,
+        });
+    }
+
+    let text = Site::new(base_url, session).input(day)?;
+
+    // Written to the side and renamed, so an interrupted write never looks cached
+    let write = || -> io::Result<()> {
+        fs::create_dir_all(path.parent().unwrap())?;
+        let partial = path.with_extension("partial");
+        fs::write(&partial, &text)?;
+        fs::rename(&partial, &path)
+    };
+    write().map_err(|err| format!("Couldn't cache {}: {}", path.display(), err))?;
+
+    Ok(Fetched {
+        path,
+        text,
+        downloaded: true,
+    })
+}
+
+// Puts the input in a day crate's `real.txt` if it's still the template's empty one. A real.txt
+// with anything in it is left alone. Returns the path if it was written.
+pub fn install(root: &Path, day: u8, text: &str) -> io::Result<Option<PathBuf>> {
+    let path = root.join(format!("day_{:02}/src/input/real.txt", day));
+    match fs::read_to_string(&path) {
+        Ok(existing) if existing.trim().is_empty() => {
+            fs::write(&path, text)?;
+            Ok(Some(path))
+        }
+        Ok(_) => Ok(None),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::tests::stand_in;
+    use std::env;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_fetch_test_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_cache_path() {
+        let cache = Path::new("cache");
+        let path = cache_path(cache, 6, "abc123");
+
+        assert!(path.starts_with("cache/2023/day_06"));
+        assert!(!path.to_string_lossy().contains("abc123"));
+        assert_eq!(path, cache_path(cache, 6, "abc123"));
+        assert_ne!(path, cache_path(cache, 6, "def456"));
+        assert_ne!(path, cache_path(cache, 7, "abc123"));
+    }
+
+    #[test]
+    fn test_fetch() {
+        let cache = temp_dir("cache");
+        let (base_url, request) = stand_in(200, "Time: 7\nDistance: 9\n");
+
+        let fetched = fetch(&cache, &base_url, "abc123", 6).unwrap();
+        assert!(fetched.downloaded);
+        assert_eq!(fetched.text, "Time: 7\nDistance: 9\n");
+        assert_eq!(fs::read_to_string(&fetched.path).unwrap(), fetched.text);
+        assert!(request
+            .join()
+            .unwrap()
+            .starts_with("GET /2023/day/6/input "));
+
+        // The stand-in only answers once, so this can only come from the cache
+        let cached = fetch(&cache, &base_url, "abc123", 6).unwrap();
+        assert!(!cached.downloaded);
+        assert_eq!(cached.path, fetched.path);
+        assert_eq!(cached.text, fetched.text);
+
+        fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error_isnt_cached() {
+        let cache = temp_dir("error");
+        let (base_url, request) = stand_in(404, "Please don't repeatedly request this endpoint");
+
+        let err = fetch(&cache, &base_url, "abc123", 25).unwrap_err();
+        assert!(err.contains("404"), "{}", err);
+        assert!(!cache_path(&cache, 25, "abc123").exists());
+        request.join().unwrap();
+
+        fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn test_install() {
+        let root = temp_dir("install");
+        let input_dir = root.join("day_14/src/input");
+        fs::create_dir_all(&input_dir).unwrap();
+        fs::write(input_dir.join("real.txt"), "").unwrap();
+
+        assert_eq!(
+            install(&root, 14, "O....#\n").unwrap(),
+            Some(input_dir.join("real.txt"))
+        );
+        assert_eq!(install(&root, 14, "#....O\n").unwrap(), None);
+        assert_eq!(
+            fs::read_to_string(input_dir.join("real.txt")).unwrap(),
+            "O....#\n"
+        );
+        assert_eq!(install(&root, 15, "...\n").unwrap(), None);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
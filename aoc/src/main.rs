@@ -0,0 +1,688 @@
+mod answers;
+mod batch;
+mod bench;
+mod dashboard;
+mod fetch;
+mod registry;
+mod render;
+mod report;
+mod scaffold;
+mod site;
+mod watch;
+
+use answers::{Answer, AnswerStore, Verdict};
+use aoc_core::trace::{self, Level};
+use aoc_core::{DynSolution, Input, Part};
+use bench::{Baseline, BenchOptions};
+use clap::{Args, Parser, Subcommand};
+use rayon::prelude::*;
+use report::{Format, Report, RunResult};
+use std::borrow::Cow;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Counts allocations, so runs can report memory use next to their timings
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOC: aoc_core::alloc::CountingAlloc = aoc_core::alloc::CountingAlloc;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day (optionally just one part), or every day with --all
+    Run {
+        #[command(flatten)]
+        select: Select,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Draw the day's state to an .svg, .ppm or .png image, a text file, or - for the terminal
+        #[arg(long, conflicts_with = "all")]
+        render: Option<PathBuf>,
+        /// Walk through how each answer was found: 1 for a summary, 2 for every step, 3 for
+        /// everything
+        #[arg(long, num_args = 0..=1, default_missing_value = "1", value_parser = explain_level)]
+        explain: Option<Level>,
+        /// Report arithmetic overflow (and any other panic) in a part, with where it happened.
+        /// Needs overflow checks: use a debug build or `--profile checked`.
+        #[arg(long)]
+        checked: bool,
+        /// Solve every input file in this directory (in parallel) and print a table of the results
+        #[arg(long, conflicts_with_all = ["all", "input", "render", "explain"])]
+        inputs: Option<PathBuf>,
+        /// How many days to run at once (defaults to one per CPU)
+        #[arg(long, short)]
+        jobs: Option<NonZeroUsize>,
+    },
+    /// Time parsing and each part over repeated runs
+    Bench {
+        #[command(flatten)]
+        select: Select,
+        /// Maximum number of runs per part
+        #[arg(long, default_value_t = 100)]
+        runs: usize,
+        /// Stop running a part after this many seconds (it always runs at least once)
+        #[arg(long, default_value_t = 3.0)]
+        max_time: f64,
+        /// Write the results to this JSON baseline file
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare against a previously saved baseline file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Flag medians more than this percent slower than the baseline as regressions
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Rerun solutions and check their answers against the accepted answers store
+    Verify {
+        #[command(flatten)]
+        select: Select,
+        /// Record answers for inputs that don't have one yet (existing answers never change)
+        #[arg(long)]
+        accept: bool,
+        /// The accepted answers store
+        #[arg(long, default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+    },
+    /// Print a synthetic input for a day, the same for the same seed and size
+    Gen {
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly how big the input is (lines, patterns, grid width...), which varies by day
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+    /// Download a day's input into the cache (once), and into its real.txt if that's still empty
+    Fetch {
+        day: u8,
+        /// Where the site is. The session token comes from AOC_SESSION or the .aoc_session file.
+        #[arg(long, default_value = site::DEFAULT_BASE_URL)]
+        base_url: String,
+        /// Where downloaded inputs are kept, by year, day and user
+        #[arg(long, default_value = fetch::DEFAULT_CACHE_DIR)]
+        cache: PathBuf,
+    },
+    /// Compute a part's answer for the real (or given) input and submit it to the site
+    Submit {
+        day: u8,
+        part: Part,
+        /// Read the input from a file (or - for stdin) instead of the embedded real input
+        #[arg(long)]
+        input: Option<String>,
+        /// Where the site is. The session token comes from AOC_SESSION or the .aoc_session file.
+        #[arg(long, default_value = site::DEFAULT_BASE_URL)]
+        base_url: String,
+        /// The accepted answers store, where a right answer is recorded
+        #[arg(long, default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+    },
+    /// Start a new day: create its crate from the template and register it with the runner
+    New { day: u8 },
+    /// Rerun a day's tests and parts whenever its source or inputs change
+    Watch { day: u8 },
+    /// An interactive dashboard of every day: statuses, answers, timings and bench history
+    Dashboard {
+        /// The accepted answers store, which the answers are checked against
+        #[arg(long, default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+    },
+    /// List the registered days and their parts
+    List,
+}
+
+#[derive(Args)]
+struct Select {
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    part: Option<Part>,
+    #[arg(long)]
+    all: bool,
+    /// Read the input from a file (or - for stdin) instead of the embedded real input
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+}
+
+impl Select {
+    fn solutions(&self) -> Vec<&'static dyn DynSolution> {
+        match self.day {
+            Some(day) if !self.all => vec![find_day(day)],
+            _ => registry::DAYS.to_vec(),
+        }
+    }
+
+    fn input(&self) -> Input {
+        self.input.as_deref().map_or(Input::Real, Input::from_arg)
+    }
+
+    fn parts(&self, solution: &dyn DynSolution) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => solution.parts().to_vec(),
+        }
+    }
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::Run {
+            select,
+            format,
+            render,
+            explain,
+            checked,
+            inputs,
+            jobs,
+        } => {
+            if checked && !aoc_core::checked::overflow_checks_enabled() {
+                exit_with("--checked needs overflow checks: run a debug build, or build with `--profile checked`");
+            }
+
+            if let Some(dir) = inputs {
+                return run_batch(&select, format, &dir);
+            }
+            if render.as_deref() == Some(Path::new("-")) && format != Format::Text {
+                exit_with("--render - draws on stdout, which would mix with the --format output; render to a file instead");
+            }
+            if aoc_core::alloc::installed() && jobs.is_some_and(|jobs| jobs.get() > 1) {
+                exit_with("--jobs > 1 would mix the days' allocation counts, which are global; counting allocations needs --jobs 1");
+            }
+
+            // Read once, since stdin can only be read once and the renderer needs it too
+            let input = select.input();
+            let days = select
+                .solutions()
+                .into_iter()
+                .map(|solution| (solution, read_input(solution, &input)))
+                .collect::<Vec<_>>();
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(jobs.map_or_else(default_jobs, NonZeroUsize::get))
+                .build()
+                .unwrap_or_else(|err| exit_with(&format!("Couldn't start threads: {}", err)));
+
+            // Days run at once, but are reported in order
+            let start = Instant::now();
+            let results = pool.install(|| {
+                days.par_iter()
+                    .map(|(solution, text)| {
+                        run_day(*solution, text, &select, &input, checked, explain)
+                    })
+                    .collect::<Vec<_>>()
+            });
+            let wall_time = start.elapsed();
+
+            let mut report = Report::new(format);
+            for (result, walkthrough) in results.into_iter().flatten() {
+                report.add(result);
+
+                // Keep machine-readable output clean
+                match walkthrough {
+                    Some(text) if format == Format::Text => {
+                        text.lines().for_each(|line| println!("    {}", line))
+                    }
+                    Some(text) => eprint!("{}", text),
+                    None => {}
+                }
+            }
+
+            report.finish();
+            if select.all && format == Format::Text {
+                print!("\n{}", report.summary(wall_time));
+            }
+            if report.failures() > 0 {
+                process::exit(1);
+            }
+
+            if let Some(path) = render {
+                render_parts(&select, &input, &days, &path);
+            }
+        }
+        Command::Bench {
+            select,
+            runs,
+            max_time,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let input = select.input();
+            let options = BenchOptions {
+                max_runs: runs,
+                max_time: Duration::from_secs_f64(max_time),
+            };
+            let previous = baseline.map(|path| {
+                Baseline::load(&path).unwrap_or_else(|err| {
+                    exit_with(&format!("Couldn't load baseline {:?}: {}", path, err))
+                })
+            });
+
+            let mut results = Baseline::default();
+            let mut regressions = 0;
+
+            for solution in select.solutions() {
+                let text = read_input(solution, &input);
+                let parts = select.parts(solution);
+
+                let results_for_day = bench::bench(solution, &text, &parts, &options)
+                    .unwrap_or_else(|err| exit_with(&err.with_input(&input).to_string()));
+
+                for result in results_for_day {
+                    let change = previous
+                        .as_ref()
+                        .and_then(|p| p.find(result.day, &result.target))
+                        .map(|previous| result.change_from(previous) * 100.0);
+                    let flag = match change {
+                        Some(change) if change > threshold => {
+                            regressions += 1;
+                            format!("{:+.1}% REGRESSION", change)
+                        }
+                        Some(change) => format!("{:+.1}%", change),
+                        None => String::new(),
+                    };
+
+                    println!(
+                        "day {:02} {:<7} min {:>10}  median {:>10}  stddev {:>10}  runs {:>4}  {}",
+                        result.day,
+                        result.target,
+                        bench::format_ns(result.min_ns),
+                        bench::format_ns(result.median_ns),
+                        bench::format_ns(result.stddev_ns),
+                        result.runs,
+                        flag
+                    );
+                    results.results.push(result);
+                }
+            }
+
+            if input == Input::Real {
+                let path = Path::new(bench::HISTORY_PATH);
+                bench::append_history(path, &results.results).unwrap_or_else(|err| {
+                    exit_with(&format!("Couldn't save history {:?}: {}", path, err))
+                });
+            }
+
+            if let Some(path) = save {
+                results.save(&path).unwrap_or_else(|err| {
+                    exit_with(&format!("Couldn't save baseline {:?}: {}", path, err))
+                });
+            }
+
+            if regressions > 0 {
+                exit_with(&format!(
+                    "{} regression(s) against the baseline",
+                    regressions
+                ));
+            }
+        }
+        Command::Verify {
+            select,
+            accept,
+            answers,
+        } => verify(&select, accept, &answers),
+        Command::Gen { day, seed, size } => match find_day(day).generate(seed, size) {
+            Some(input) => println!("{}", input),
+            None => exit_with(&format!("Day {} has no generator", day)),
+        },
+        Command::Fetch {
+            day,
+            base_url,
+            cache,
+        } => {
+            let session = site::session().unwrap_or_else(|err| exit_with(&err));
+            let fetched = fetch::fetch(&cache, &base_url, &session, day)
+                .unwrap_or_else(|err| exit_with(&format!("Couldn't fetch day {}: {}", day, err)));
+            let how = if fetched.downloaded {
+                "downloaded"
+            } else {
+                "cached"
+            };
+            println!("{} ({})", fetched.path.display(), how);
+
+            let root = Path::new(scaffold::ROOT);
+            match fetch::install(root, day, &fetched.text) {
+                Ok(Some(path)) => {
+                    println!("{}", path.strip_prefix(root).unwrap_or(&path).display())
+                }
+                Ok(None) => {}
+                Err(err) => exit_with(&format!("Couldn't install day {}'s input: {}", day, err)),
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            input,
+            base_url,
+            answers,
+        } => {
+            let input = input.as_deref().map_or(Input::Real, Input::from_arg);
+            submit(find_day(day), part, &input, &base_url, &answers)
+        }
+        Command::New { day } => {
+            let root = Path::new(scaffold::ROOT);
+            let changed = scaffold::new_day(root, day)
+                .unwrap_or_else(|err| exit_with(&format!("Couldn't create day {}: {}", day, err)));
+            for path in changed {
+                println!("{}", path.strip_prefix(root).unwrap_or(&path).display());
+            }
+        }
+        Command::Watch { day } => watch::watch(day)
+            .unwrap_or_else(|err| exit_with(&format!("Couldn't watch day {}: {}", day, err))),
+        Command::Dashboard { answers } => dashboard::dashboard(&answers)
+            .unwrap_or_else(|err| exit_with(&format!("Dashboard failed: {}", err))),
+        Command::List => {
+            for solution in registry::DAYS {
+                let parts = solution.parts().iter().map(Part::to_string);
+                println!(
+                    "day {:02}: parts {}",
+                    solution.day(),
+                    parts.collect::<Vec<_>>().join(", ")
+                );
+            }
+        }
+    }
+}
+
+// Each part's result, with its walkthrough if explaining
+fn run_day(
+    solution: &dyn DynSolution,
+    text: &str,
+    select: &Select,
+    input: &Input,
+    checked: bool,
+    explain: Option<Level>,
+) -> Vec<(RunResult, Option<String>)> {
+    let mut results = vec![];
+
+    for part in select.parts(solution) {
+        let result = RunResult::new(solution.day(), part, input.to_string());
+        let run = || match checked {
+            true => aoc_core::checked::catch(|| solution.run_timed(text, part)),
+            false => Ok(solution.run_timed(text, part)),
+        };
+        let (run, walkthrough) = match explain {
+            Some(level) => {
+                let (run, trace) = trace::explain(level, run);
+                (run, Some(trace.render()))
+            }
+            None => (run(), None),
+        };
+
+        match run {
+            Ok(Ok(timed)) => {
+                let mut result = result.answered(timed.answer, timed.parse + timed.solve);
+                if aoc_core::alloc::installed() {
+                    result = result.with_memory(timed.parse_alloc, timed.solve_alloc);
+                }
+                results.push((result, walkthrough));
+            }
+            Ok(Err(err)) => {
+                // Every part parses the same input, so they'd all fail the same way
+                results.push((result.failed(err.with_input(input).to_string()), None));
+                break;
+            }
+            Err(panic) => {
+                let message = format!(
+                    "day {:02} part {} {}: {}",
+                    solution.day(),
+                    part,
+                    input,
+                    panic
+                );
+                results.push((result.failed(message), None));
+            }
+        }
+    }
+
+    results
+}
+
+// A thread per CPU, or just the one when counting allocations, since the counts are global
+fn default_jobs() -> usize {
+    if aoc_core::alloc::installed() {
+        return 1;
+    }
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+fn run_batch(select: &Select, format: Format, dir: &Path) {
+    let files = batch::input_files(dir)
+        .unwrap_or_else(|err| exit_with(&format!("Couldn't read {:?}: {}", dir, err)));
+    if files.is_empty() {
+        exit_with(&format!("No input files in {:?}", dir));
+    }
+
+    let solution = select.solutions()[0];
+    let results = batch::run(solution, &select.parts(solution), &files);
+    let failures = results.iter().filter(|r| r.error.is_some()).count();
+
+    match format {
+        Format::Text => print!("{}", batch::table(&results)),
+        _ => {
+            let mut report = Report::new(format);
+            results.into_iter().for_each(|result| report.add(result));
+            report.finish();
+        }
+    }
+
+    if failures > 0 {
+        exit_with(&format!("{} of {} input(s) failed", failures, files.len()));
+    }
+}
+
+fn verify(select: &Select, accept: bool, path: &Path) {
+    let mut store = AnswerStore::load(path)
+        .unwrap_or_else(|err| exit_with(&format!("Couldn't load answers {:?}: {}", path, err)));
+    let input = select.input();
+    let (mut failures, mut recorded) = (0, 0);
+
+    for solution in select.solutions() {
+        let parts = select.parts(solution);
+        let fixtures = solution.fixtures();
+        let label = |part: Part, input: &Input| {
+            format!("day {:02} part {} {}:", solution.day(), part, input)
+        };
+
+        // The fixture answers from answers.toml
+        for (test, part, expected) in fixtures.answers {
+            if !parts.contains(part) {
+                continue;
+            }
+
+            let test = Input::Test(*test);
+            let text = read_input(solution, &test);
+            match solution.run(&text, *part) {
+                Ok(answer) if answer == *expected => println!("{} ok", label(*part, &test)),
+                Ok(answer) => {
+                    failures += 1;
+                    println!(
+                        "{} MISMATCH (got {}, expected {})",
+                        label(*part, &test),
+                        answer,
+                        expected
+                    );
+                }
+                Err(err) => {
+                    failures += 1;
+                    println!("{} ERROR\n{}", label(*part, &test), err.with_input(&test));
+                }
+            }
+        }
+
+        // The real (or given) input against the store
+        let text = read_input(solution, &input);
+        let input_hash = answers::input_hash(&text);
+        for part in parts {
+            let answer = match solution.run(&text, part) {
+                Ok(answer) => answer,
+                Err(err) => {
+                    failures += 1;
+                    println!("{} ERROR\n{}", label(part, &input), err.with_input(&input));
+                    break;
+                }
+            };
+
+            match store.verify(solution.day(), part.number(), &input_hash, &answer) {
+                Verdict::Correct => println!("{} ok", label(part, &input)),
+                Verdict::Mismatch { expected } => {
+                    failures += 1;
+                    println!(
+                        "{} MISMATCH (got {}, expected {})",
+                        label(part, &input),
+                        answer,
+                        expected
+                    );
+                }
+                Verdict::Unrecorded if accept => {
+                    println!("{} NEW {} (recorded)", label(part, &input), answer);
+                    recorded += 1;
+                    store.record(Answer {
+                        day: solution.day(),
+                        part: part.number(),
+                        input_hash: input_hash.clone(),
+                        answer,
+                    });
+                }
+                Verdict::Unrecorded => {
+                    println!(
+                        "{} NEW {} (use --accept to record it)",
+                        label(part, &input),
+                        answer
+                    )
+                }
+            }
+        }
+    }
+
+    if recorded > 0 {
+        store
+            .save(path)
+            .unwrap_or_else(|err| exit_with(&format!("Couldn't save answers {:?}: {}", path, err)));
+    }
+
+    if failures > 0 {
+        exit_with(&format!("{} answer(s) failed verification", failures));
+    }
+}
+
+fn submit(solution: &dyn DynSolution, part: Part, input: &Input, base_url: &str, path: &Path) {
+    let mut store = AnswerStore::load(path)
+        .unwrap_or_else(|err| exit_with(&format!("Couldn't load answers {:?}: {}", path, err)));
+    let text = read_input(solution, input);
+    let input_hash = answers::input_hash(&text);
+    let answer = solution
+        .run(&text, part)
+        .unwrap_or_else(|err| exit_with(&err.with_input(input).to_string()));
+    let label = format!("day {:02} part {} {}:", solution.day(), part, input);
+
+    // Don't spend a submission (or a timeout) on something we already know
+    match store.verify(solution.day(), part.number(), &input_hash, &answer) {
+        Verdict::Correct => {
+            println!("{} {} was already accepted", label, answer);
+            return;
+        }
+        Verdict::Mismatch { expected } => exit_with(&format!(
+            "{} got {}, but {} was already accepted",
+            label, answer, expected
+        )),
+        Verdict::Unrecorded => {}
+    }
+
+    let session = site::session().unwrap_or_else(|err| exit_with(&err));
+    let outcome = site::Site::new(base_url, &session)
+        .submit(solution.day(), part, &answer)
+        .unwrap_or_else(|err| exit_with(&format!("Couldn't submit: {}", err)));
+
+    match outcome {
+        site::Outcome::Correct => {
+            println!("{} {} is RIGHT (recorded)", label, answer);
+            store.record(Answer {
+                day: solution.day(),
+                part: part.number(),
+                input_hash,
+                answer,
+            });
+            store.save(path).unwrap_or_else(|err| {
+                exit_with(&format!("Couldn't save answers {:?}: {}", path, err))
+            });
+        }
+        site::Outcome::Wrong { hint } => exit_with(&format!(
+            "{} {} is WRONG{}",
+            label,
+            answer,
+            hint.map(|hint| format!(" ({})", hint)).unwrap_or_default()
+        )),
+        site::Outcome::TooSoon { wait } => exit_with(&format!(
+            "{} submitted too recently, wait {}",
+            label,
+            wait.as_deref().unwrap_or("a minute")
+        )),
+        site::Outcome::WrongLevel => exit_with(&format!(
+            "{} the site isn't taking answers for this part (already solved, or not unlocked)",
+            label
+        )),
+        site::Outcome::Unknown(text) => {
+            exit_with(&format!("{} unexpected response: {}", label, text))
+        }
+    }
+}
+
+fn explain_level(level: &str) -> Result<Level, String> {
+    level
+        .parse()
+        .ok()
+        .and_then(Level::from_number)
+        .ok_or_else(|| format!("expected 1, 2 or 3, not {}", level))
+}
+
+fn render_parts(
+    select: &Select,
+    input: &Input,
+    days: &[(&'static dyn DynSolution, Cow<'static, str>)],
+    path: &Path,
+) {
+    for (solution, text) in days {
+        let parts = select.parts(*solution);
+
+        for part in parts.iter() {
+            let frame = match solution.render(text, *part) {
+                Ok(Some(frame)) => frame,
+                Ok(None) => exit_with(&format!("Day {} can't be rendered", solution.day())),
+                Err(err) => exit_with(&err.with_input(input).to_string()),
+            };
+
+            let path = render::part_path(path, *part, parts.len() > 1);
+            render::write_frame(&frame, &path).unwrap_or_else(|err| {
+                exit_with(&format!("Couldn't render to {:?}: {}", path, err))
+            });
+        }
+    }
+}
+
+// The input's text, warning if the days will see it differently (CRLF line endings, say)
+fn read_input(solution: &dyn DynSolution, input: &Input) -> Cow<'static, str> {
+    let text = solution.fixtures().text(input).unwrap_or_else(|err| {
+        exit_with(&format!("Couldn't read input {:?}: {}", input, err));
+    });
+    if let Some(warning) = aoc_core::normalize(&text).warning(input) {
+        eprintln!("warning: {}", warning);
+    }
+    text
+}
+
+fn find_day(day: u8) -> &'static dyn DynSolution {
+    registry::find(day).unwrap_or_else(|| exit_with(&format!("Day {} isn't solved yet", day)))
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
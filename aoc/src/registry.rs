@@ -0,0 +1,38 @@
+use aoc_core::DynSolution;
+
+pub static DAYS: &[&dyn DynSolution] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn DynSolution> {
+    DAYS.iter().find(|solution| solution.day() == day).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_registered_in_order() {
+        let days = DAYS.iter().map(|s| s.day()).collect::<Vec<_>>();
+        assert_eq!(days, (1..=DAYS.len() as u8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(5).map(|s| s.day()), Some(5));
+        assert!(find(25).is_none());
+    }
+}
@@ -0,0 +1,96 @@
+use aoc_core::{Frame, Part};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+// Pixels per cell in PPM and PNG images
+const SCALE: usize = 4;
+
+// Draws a frame in the format matching the path's extension: `.svg`, `.ppm` or `.png` images, or
+// ANSI-colored text for anything else. `-` prints the text to the terminal.
+pub fn write_frame(frame: &Frame, path: &Path) -> io::Result<()> {
+    if path == Path::new("-") {
+        return io::stdout().write_all(frame.to_ansi().as_bytes());
+    }
+
+    let extension = path.extension().and_then(|e| e.to_str());
+    match extension.map(str::to_ascii_lowercase).as_deref() {
+        Some("svg") => std::fs::write(path, frame.to_svg()),
+        Some("ppm") => std::fs::write(path, frame.to_ppm(SCALE)),
+        Some("png") => write_png(frame, path),
+        _ => std::fs::write(path, frame.to_ansi()),
+    }
+}
+
+fn write_png(frame: &Frame, path: &Path) -> io::Result<()> {
+    let (width, height, pixels) = frame.to_rgb(SCALE);
+
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(File::create(path)?),
+        width as u32,
+        height as u32,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer.write_image_data(&pixels).map_err(io::Error::other)
+}
+
+// When rendering more than one part, each gets its own file: `loop.svg` becomes `loop_part_1.svg`
+pub fn part_path(path: &Path, part: Part, several_parts: bool) -> PathBuf {
+    if !several_parts || path == Path::new("-") {
+        return path.to_path_buf();
+    }
+
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{}_part_{}.{}", stem, part, extension.to_string_lossy()),
+        None => format!("{}_part_{}", stem, part),
+    };
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{Grid, Highlight, Pos};
+
+    #[test]
+    fn test_part_path() {
+        let path = Path::new("out/loop.svg");
+        assert_eq!(part_path(path, Part::One, false), Path::new("out/loop.svg"));
+        assert_eq!(
+            part_path(path, Part::Two, true),
+            Path::new("out/loop_part_2.svg")
+        );
+        assert_eq!(
+            part_path(Path::new("loop"), Part::One, true),
+            Path::new("loop_part_1")
+        );
+        assert_eq!(part_path(Path::new("-"), Part::One, true), Path::new("-"));
+    }
+
+    #[test]
+    fn test_write_frame() {
+        let mut frame = Frame::new(Grid::from_lines(&["#.", ".#"]).unwrap());
+        frame.highlight(Pos::new(0, 0), Highlight::Green);
+
+        let dir = std::env::temp_dir();
+        let id = std::process::id();
+        for extension in ["svg", "ppm", "png", "txt"] {
+            let path = dir.join(format!("aoc_render_{}.{}", id, extension));
+            write_frame(&frame, &path).unwrap();
+
+            let written = std::fs::read(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            let magic: &[u8] = match extension {
+                "svg" => b"<svg",
+                "ppm" => b"P6",
+                "png" => b"\x89PNG",
+                _ => b"\x1b[1;32m#",
+            };
+            assert!(written.starts_with(magic), "{}", extension);
+        }
+    }
+}
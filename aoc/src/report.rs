@@ -0,0 +1,349 @@
+use aoc_core::alloc::{self, AllocStats};
+use aoc_core::Part;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(ValueEnum, Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Serialize, Eq, PartialEq, Clone, Debug)]
+pub struct RunResult {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub elapsed_ns: u64,
+    // Only with the counting allocator (the `alloc-stats` feature)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Memory>,
+}
+
+#[derive(Serialize, Eq, PartialEq, Clone, Debug)]
+pub struct Memory {
+    pub parse: HeapUse,
+    pub solve: HeapUse,
+}
+
+#[derive(Serialize, Eq, PartialEq, Clone, Debug)]
+pub struct HeapUse {
+    pub peak_bytes: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+impl From<AllocStats> for HeapUse {
+    fn from(stats: AllocStats) -> HeapUse {
+        HeapUse {
+            peak_bytes: stats.peak_bytes,
+            allocations: stats.allocations,
+            allocated_bytes: stats.allocated_bytes,
+        }
+    }
+}
+
+impl Display for HeapUse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocations, {} allocated",
+            format_bytes(self.peak_bytes),
+            self.allocations,
+            format_bytes(self.allocated_bytes)
+        )
+    }
+}
+
+impl RunResult {
+    pub fn new(day: u8, part: Part, input: String) -> RunResult {
+        RunResult {
+            day,
+            part: part.number(),
+            input,
+            answer: None,
+            error: None,
+            elapsed_ns: 0,
+            memory: None,
+        }
+    }
+
+    pub fn answered(mut self, answer: String, elapsed: Duration) -> RunResult {
+        self.answer = Some(answer);
+        self.elapsed_ns = elapsed.as_nanos() as u64;
+        self
+    }
+
+    pub fn with_memory(mut self, parse: AllocStats, solve: AllocStats) -> RunResult {
+        self.memory = Some(Memory {
+            parse: parse.into(),
+            solve: solve.into(),
+        });
+        self
+    }
+
+    pub fn failed(mut self, error: String) -> RunResult {
+        self.error = Some(error);
+        self
+    }
+}
+
+// How many of the slowest parts the summary lists
+const SLOWEST: usize = 5;
+
+// Prints results as they come in. Text keeps the original `day: 01` / `  part 1: N` layout (with
+// errors going to stderr), CSV gets a header row, and JSON is a single array printed at the end.
+// With the counting allocator, text adds the time and memory use under each part, and CSV adds
+// memory columns.
+pub struct Report {
+    format: Format,
+    memory: bool,
+    results: Vec<RunResult>,
+}
+
+impl Report {
+    pub fn new(format: Format) -> Report {
+        let memory = alloc::installed();
+        if format == Format::Csv {
+            println!("{}", csv_header(memory));
+        }
+
+        Report {
+            format,
+            memory,
+            results: vec![],
+        }
+    }
+
+    pub fn add(&mut self, result: RunResult) {
+        match self.format {
+            Format::Text => {
+                let new_day = self
+                    .results
+                    .last()
+                    .is_none_or(|last| last.day != result.day);
+                if new_day {
+                    println!("day: {:02}", result.day);
+                }
+
+                match (&result.answer, &result.error) {
+                    (Some(answer), _) => match &result.memory {
+                        Some(memory) => {
+                            let elapsed = Duration::from_nanos(result.elapsed_ns);
+                            println!("  part {}: {}  ({:.2?})", result.part, answer, elapsed);
+                            println!("    parse: {}", memory.parse);
+                            println!("    solve: {}", memory.solve);
+                        }
+                        None => println!("  part {}: {}", result.part, answer),
+                    },
+                    (None, Some(error)) => eprintln!("{}", error),
+                    (None, None) => {}
+                }
+            }
+            Format::Csv => println!("{}", csv_row(&result, self.memory)),
+            Format::Json => {}
+        }
+
+        self.results.push(result);
+    }
+
+    pub fn failures(&self) -> usize {
+        self.results.iter().filter(|r| r.error.is_some()).count()
+    }
+
+    // How long everything took against the time spent in the parts themselves (more, when days
+    // ran at once), and the slowest parts
+    pub fn summary(&self, wall_time: Duration) -> String {
+        let in_parts = self.results.iter().map(|r| r.elapsed_ns).sum::<u64>();
+        let mut summary = format!(
+            "{} parts ({} failed) in {:.2?}, {:.2?} in the parts themselves\n",
+            self.results.len(),
+            self.failures(),
+            wall_time,
+            Duration::from_nanos(in_parts)
+        );
+
+        let mut answered = self
+            .results
+            .iter()
+            .filter(|r| r.answer.is_some())
+            .collect::<Vec<_>>();
+        answered.sort_by_key(|r| std::cmp::Reverse(r.elapsed_ns));
+        if !answered.is_empty() {
+            summary += "slowest:\n";
+        }
+        for result in answered.iter().take(SLOWEST) {
+            summary += &format!(
+                "  day {:02} part {}  {:>10.2?}\n",
+                result.day,
+                result.part,
+                Duration::from_nanos(result.elapsed_ns)
+            );
+        }
+
+        summary
+    }
+
+    pub fn finish(&self) {
+        if self.format == Format::Json {
+            println!("{}", serde_json::to_string_pretty(&self.results).unwrap());
+        }
+    }
+}
+
+const MEMORY_COLUMNS: [&str; 6] = [
+    "parse_peak_bytes",
+    "parse_allocations",
+    "parse_allocated_bytes",
+    "solve_peak_bytes",
+    "solve_allocations",
+    "solve_allocated_bytes",
+];
+
+fn csv_header(memory: bool) -> String {
+    let mut header = "day,part,input,answer,error,elapsed_ns".to_string();
+    if memory {
+        header += &format!(",{}", MEMORY_COLUMNS.join(","));
+    }
+    header
+}
+
+fn csv_row(result: &RunResult, memory: bool) -> String {
+    let mut row = vec![
+        result.day.to_string(),
+        result.part.to_string(),
+        csv_field(&result.input),
+        csv_field(result.answer.as_deref().unwrap_or_default()),
+        csv_field(result.error.as_deref().unwrap_or_default()),
+        result.elapsed_ns.to_string(),
+    ];
+    if memory {
+        match &result.memory {
+            Some(Memory { parse, solve }) => {
+                for heap in [parse, solve] {
+                    row.push(heap.peak_bytes.to_string());
+                    row.push(heap.allocations.to_string());
+                    row.push(heap.allocated_bytes.to_string());
+                }
+            }
+            None => row.extend(MEMORY_COLUMNS.map(|_| String::new())),
+        }
+    }
+    row.join(",")
+}
+
+// Like `1.50 KiB`
+pub fn format_bytes(bytes: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.2} {}", size, units[unit]),
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(peak_bytes: u64, allocations: u64, allocated_bytes: u64) -> AllocStats {
+        AllocStats {
+            peak_bytes,
+            allocations,
+            allocated_bytes,
+        }
+    }
+
+    #[test]
+    fn test_csv_row() {
+        let result = RunResult::new(5, Part::Two, "real.txt".to_string())
+            .answered("46".to_string(), Duration::from_micros(3));
+        assert_eq!(csv_row(&result, false), "5,2,real.txt,46,,3000");
+        assert_eq!(csv_row(&result, true), "5,2,real.txt,46,,3000,,,,,,");
+
+        let result = result.with_memory(stats(10, 2, 30), stats(0, 0, 0));
+        assert_eq!(
+            csv_row(&result, true),
+            "5,2,real.txt,46,,3000,10,2,30,0,0,0"
+        );
+
+        let result = RunResult::new(7, Part::One, "my, \"input\".txt".to_string())
+            .failed("stdin:2:4: expected a card\n  |".to_string());
+        assert_eq!(
+            csv_row(&result, false),
+            "7,1,\"my, \"\"input\"\".txt\",,\"stdin:2:4: expected a card\n  |\",0"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let result = RunResult::new(1, Part::One, "test_1.txt".to_string())
+            .answered("142".to_string(), Duration::from_nanos(1500));
+        assert_eq!(
+            serde_json::to_string(&result).unwrap(),
+            r#"{"day":1,"part":1,"input":"test_1.txt","answer":"142","error":null,"elapsed_ns":1500}"#
+        );
+
+        let result = result.with_memory(stats(10, 2, 30), stats(5, 1, 5));
+        assert_eq!(
+            serde_json::to_string(&result.memory).unwrap(),
+            r#"{"parse":{"peak_bytes":10,"allocations":2,"allocated_bytes":30},"solve":{"peak_bytes":5,"allocations":1,"allocated_bytes":5}}"#
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut report = Report {
+            format: Format::Json,
+            memory: false,
+            results: vec![],
+        };
+        for (day, ms) in [(1, 3), (2, 40), (3, 1), (4, 2), (5, 9), (6, 5)] {
+            let result = RunResult::new(day, Part::One, "real.txt".to_string());
+            report.add(result.answered(day.to_string(), Duration::from_millis(ms)));
+        }
+        report.add(RunResult::new(7, Part::Two, "real.txt".to_string()).failed("bad".to_string()));
+
+        assert_eq!(
+            report.summary(Duration::from_millis(45)),
+            "7 parts (1 failed) in 45.00ms, 60.00ms in the parts themselves\n\
+             slowest:\n\
+             \x20 day 02 part 1     40.00ms\n\
+             \x20 day 05 part 1      9.00ms\n\
+             \x20 day 06 part 1      5.00ms\n\
+             \x20 day 01 part 1      3.00ms\n\
+             \x20 day 04 part 1      2.00ms\n"
+        );
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(5 << 20), "5.00 MiB");
+        assert_eq!(
+            HeapUse::from(stats(2048, 3, 4096)).to_string(),
+            "peak 2.00 KiB, 3 allocations, 4.00 KiB allocated"
+        );
+    }
+}
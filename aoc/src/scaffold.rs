@@ -0,0 +1,186 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// The workspace root, which holds the day crates
+pub const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+// A new day's files, relative to its crate, with `{{DAY}}` (zero-padded) and `{{DAY_NUMBER}}`
+// placeholders
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/day/Cargo.toml")),
+    ("build.rs", include_str!("../templates/day/build.rs")),
+    ("src/main.rs", include_str!("../templates/day/src/main.rs")),
+    ("src/lib.rs", include_str!("../templates/day/src/lib.rs")),
+    ("src/data.rs", include_str!("../templates/day/src/data.rs")),
+    (
+        "src/generator.rs",
+        include_str!("../templates/day/src/generator.rs"),
+    ),
+    (
+        "src/input/real.txt",
+        include_str!("../templates/day/src/input/real.txt"),
+    ),
+    (
+        "src/input/test_1.txt",
+        include_str!("../templates/day/src/input/test_1.txt"),
+    ),
+    (
+        "src/input/answers.toml",
+        include_str!("../templates/day/src/input/answers.toml"),
+    ),
+];
+
+// Creates the `day_NN` crate under `root` from the templates, then registers it with the runner
+// (and the fuzz targets, if there are any). Returns the paths it created or changed.
+pub fn new_day(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(invalid(format!("There's no day {}", day)));
+    }
+
+    let padded = format!("{:02}", day);
+    let dir = root.join(format!("day_{}", padded));
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("day_{} already exists", padded),
+        ));
+    }
+
+    // Check the registrations will work before creating anything
+    let registry_path = root.join("aoc/src/registry.rs");
+    let manifest_path = root.join("aoc/Cargo.toml");
+    let registry = register(
+        &fs::read_to_string(&registry_path)?,
+        |line| line.starts_with("    &day_"),
+        format!("    &day_{}::Day{},", padded, padded),
+    )?;
+    let manifest = register(
+        &fs::read_to_string(&manifest_path)?,
+        |line| line.starts_with("day_"),
+        format!("day_{} = {{ path = \"../day_{}\" }}", padded, padded),
+    )?;
+
+    let mut changed = vec![];
+    for (path, template) in TEMPLATES {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap())?;
+        let text = template
+            .replace("{{DAY}}", &padded)
+            .replace("{{DAY_NUMBER}}", &day.to_string());
+        fs::write(&path, text)?;
+        changed.push(path);
+    }
+
+    fs::write(&registry_path, registry)?;
+    fs::write(&manifest_path, manifest)?;
+    changed.extend([registry_path, manifest_path]);
+
+    let fuzz_path = root.join("fuzz/Cargo.toml");
+    if fuzz_path.exists() {
+        let fuzz = register(
+            &fs::read_to_string(&fuzz_path)?,
+            |line| line.starts_with("day_"),
+            format!("day_{} = {{ path = \"../day_{}\" }}", padded, padded),
+        )?;
+        let target = format!(
+            "\n[[bin]]\nname = \"day_{0}\"\npath = \"fuzz_targets/day_{0}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+            padded
+        );
+        fs::write(&fuzz_path, fuzz + &target)?;
+
+        let target_path = root.join(format!("fuzz/fuzz_targets/day_{}.rs", padded));
+        fs::write(&target_path, fuzz_target(&padded))?;
+        changed.extend([fuzz_path, target_path]);
+    }
+
+    Ok(changed)
+}
+
+fn fuzz_target(padded: &str) -> String {
+    format!(
+        "#![no_main]\n\nuse aoc_core::Solution;\nuse libfuzzer_sys::fuzz_target;\n\nfuzz_target!(|input: &str| {{\n    let _ = day_{0}::Day{0}::parse(input);\n}});\n",
+        padded
+    )
+}
+
+// Adds `entry` to the lines of `text` that `is_entry` picks out, keeping them sorted
+fn register(text: &str, is_entry: impl Fn(&str) -> bool, entry: String) -> io::Result<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<usize> = (0..lines.len())
+        .filter(|idx| is_entry(lines[*idx]))
+        .collect();
+
+    let Some(last) = entries.last() else {
+        return Err(invalid(format!("Nowhere to register `{}`", entry.trim())));
+    };
+    let idx = entries
+        .iter()
+        .find(|idx| lines[**idx] > entry.as_str())
+        .map_or(last + 1, |idx| *idx);
+
+    lines.insert(idx, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_register() {
+        let registry = "pub static DAYS = &[\n    &day_01::Day01,\n    &day_03::Day03,\n];\n";
+        let is_entry = |line: &str| line.starts_with("    &day_");
+
+        assert_eq!(
+            register(registry, is_entry, "    &day_02::Day02,".to_string()).unwrap(),
+            "pub static DAYS = &[\n    &day_01::Day01,\n    &day_02::Day02,\n    &day_03::Day03,\n];\n"
+        );
+        assert_eq!(
+            register(registry, is_entry, "    &day_14::Day14,".to_string()).unwrap(),
+            "pub static DAYS = &[\n    &day_01::Day01,\n    &day_03::Day03,\n    &day_14::Day14,\n];\n"
+        );
+        assert!(register("", is_entry, "    &day_14::Day14,".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc_scaffold_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("aoc/src/registry.rs"),
+            "pub static DAYS = &[\n    &day_01::Day01,\n];\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nclap = \"*\"\nday_01 = { path = \"../day_01\" }\npng = \"*\"\n",
+        )
+        .unwrap();
+
+        let changed = new_day(&root, 14).unwrap();
+        assert_eq!(changed.len(), TEMPLATES.len() + 2);
+
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert!(read("day_14/Cargo.toml").contains("name = \"day_14\""));
+        assert!(read("day_14/src/lib.rs").contains("const DAY: u8 = 14;"));
+        assert!(read("day_14/src/main.rs").contains("day_14::Day14"));
+        assert!(!read("day_14/src/generator.rs").contains("{{"));
+        assert!(read("aoc/src/registry.rs").contains("    &day_01::Day01,\n    &day_14::Day14,\n"));
+        assert!(read("aoc/Cargo.toml").contains("day_14 = { path = \"../day_14\" }\npng"));
+
+        assert_eq!(
+            new_day(&root, 14).unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+        assert!(new_day(&root, 26).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,299 @@
+use aoc_core::Part;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2023;
+
+// Where the session token is read from when AOC_SESSION isn't set. It's gitignored.
+pub const SESSION_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../.aoc_session");
+
+const USER_AGENT: &str = "github.com/scott113341/advent_of_code_2023 aoc runner";
+
+// What the site said about a submitted answer
+#[derive(Eq, PartialEq, Debug)]
+pub enum Outcome {
+    Correct,
+    // With "too high" or "too low" when the site says so
+    Wrong { hint: Option<String> },
+    // With how long is left to wait, like "36s"
+    TooSoon { wait: Option<String> },
+    // The part was already solved (or isn't unlocked yet)
+    WrongLevel,
+    // Anything else, as the page's text
+    Unknown(String),
+}
+
+pub struct Site {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Site {
+    pub fn new(base_url: &str, session: &str) -> Site {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Site {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent,
+        }
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call()
+            .map_err(|err| format!("GET {}: {}", url, err))?;
+
+        let status = response.status();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| format!("GET {}: {}", url, err))?;
+        if !status.is_success() {
+            return Err(format!("GET {}: {} {}", url, status, first_line(&body)));
+        }
+
+        Ok(body)
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Outcome, String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+        let level = part.number().to_string();
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .map_err(|err| format!("POST {}: {}", url, err))?;
+
+        let status = response.status();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| format!("POST {}: {}", url, err))?;
+        if !status.is_success() {
+            return Err(format!("POST {}: {} {}", url, status, first_line(&body)));
+        }
+
+        Ok(parse_outcome(&body))
+    }
+}
+
+// The token from AOC_SESSION, or else the session file
+pub fn session() -> Result<String, String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let path = Path::new(SESSION_FILE);
+    match fs::read_to_string(path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(format!(
+            "No session token: set AOC_SESSION or put it in {}",
+            path.display()
+        )),
+    }
+}
+
+pub fn parse_outcome(html: &str) -> Outcome {
+    let text = page_text(html);
+
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = ["too high", "too low"]
+            .into_iter()
+            .find(|hint| text.contains(&format!("your answer is {}", hint)));
+        Outcome::Wrong {
+            hint: hint.map(str::to_string),
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_string());
+        Outcome::TooSoon { wait }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+// The text of the page's <article> (or the whole page), without tags and with whitespace collapsed
+fn page_text(html: &str) -> String {
+    // Keeping the "<article" makes it a tag to skip like any other
+    let article = html
+        .find("<article")
+        .and_then(|start| html[start..].split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or("").trim()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    // A stand-in for the site that answers one request with `status` and `body`, and hands back
+    // the request it got (request line, headers and body)
+    pub(crate) fn stand_in(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 {} X\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(
+            parse_outcome(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_outcome(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck..."
+            )),
+            Outcome::Wrong {
+                hint: Some("too high".to_string())
+            }
+        );
+        assert_eq!(
+            parse_outcome(&page("That's not the right answer.  If you're stuck...")),
+            Outcome::Wrong { hint: None }
+        );
+        assert_eq!(
+            parse_outcome(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 36s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a>")),
+            Outcome::TooSoon {
+                wait: Some("36s".to_string())
+            }
+        );
+        assert_eq!(
+            parse_outcome(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::WrongLevel
+        );
+        assert_eq!(
+            parse_outcome(&page("Something <em>new</em>\n  happened.")),
+            Outcome::Unknown("Something new happened.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, request) = stand_in(200, &page("That's the right answer!"));
+        let site = Site::new(&format!("{}/", base_url), "abc123");
+
+        assert_eq!(site.submit(7, Part::Two, "5905"), Ok(Outcome::Correct));
+
+        let request = request.join().unwrap();
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=5905"));
+        assert!(request.starts_with("POST /2023/day/7/answer HTTP/1.1\r\n"));
+        let request = request.to_lowercase();
+        assert!(
+            request.contains("\r\ncookie: session=abc123\r\n"),
+            "{}",
+            request
+        );
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, request) = stand_in(200, "1abc2\npqr3stu8vwx\n");
+        let site = Site::new(&base_url, "abc123");
+
+        assert_eq!(site.input(1), Ok("1abc2\npqr3stu8vwx\n".to_string()));
+
+        let request = request.join().unwrap().to_lowercase();
+        assert!(request.starts_with("get /2023/day/1/input http/1.1\r\n"));
+        assert!(
+            request.contains("\r\ncookie: session=abc123\r\n"),
+            "{}",
+            request
+        );
+    }
+
+    #[test]
+    fn test_submit_error_status() {
+        let (base_url, request) = stand_in(400, "Bad session\n");
+        let site = Site::new(&base_url, "expired");
+
+        let err = site.submit(1, Part::One, "142").unwrap_err();
+        assert!(err.contains("/2023/day/1/answer: 400"), "{}", err);
+        assert!(err.ends_with("Bad session"), "{}", err);
+        request.join().unwrap();
+    }
+}
@@ -0,0 +1,159 @@
+use crate::scaffold;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+use std::{env, io};
+
+// Editors tend to write a file in a few steps, so wait for things to settle before rerunning
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+// Reruns the day's tests and parts whenever its crate (or aoc_core) changes, until interrupted
+pub fn watch(day: u8) -> notify::Result<()> {
+    let root = Path::new(scaffold::ROOT);
+    let package = format!("day_{:02}", day);
+    let dir = root.join(&package);
+    if !dir.is_dir() {
+        return Err(notify::Error::io(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} doesn't exist", package),
+        )));
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(&dir, RecursiveMode::Recursive)?;
+    watcher.watch(&root.join("aoc_core/src"), RecursiveMode::Recursive)?;
+
+    let mut previous = None;
+    loop {
+        previous = Some(run(root, &package, previous.as_deref()));
+        println!("\nWatching {} for changes...", package);
+
+        // Block until something changes, then soak up everything else that changes with it
+        loop {
+            let event = rx.recv().expect("the watcher stopped")?;
+            if !matches!(event.kind, EventKind::Access(_)) {
+                break;
+            }
+        }
+        while rx.recv_timeout(SETTLE_TIME).is_ok() {}
+    }
+}
+
+// Runs the tests then the parts, printing how the answers compare to `previous`. Returns the new
+// answers, or `previous` again if the parts couldn't run.
+fn run(root: &Path, package: &str, previous: Option<&[(String, String)]>) -> Vec<(String, String)> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    println!("\n=== cargo test -p {}", package);
+    let tests = Command::new(&cargo)
+        .args(["test", "--quiet", "-p", package])
+        .current_dir(root)
+        .status();
+    match tests {
+        Ok(status) if status.success() => println!("tests passed"),
+        Ok(_) => println!("TESTS FAILED"),
+        Err(err) => println!("Couldn't run cargo test: {}", err),
+    }
+
+    println!("\n=== cargo run --bin {}", package);
+    let output = Command::new(&cargo)
+        .args(["run", "--release", "--quiet", "--bin", package])
+        .current_dir(root)
+        .stderr(Stdio::inherit())
+        .output();
+    let answers = match output {
+        Ok(output) if output.status.success() => {
+            parse_answers(&String::from_utf8_lossy(&output.stdout))
+        }
+        Ok(_) => {
+            println!("The parts didn't run");
+            return previous.map(<[_]>::to_vec).unwrap_or_default();
+        }
+        Err(err) => {
+            println!("Couldn't run cargo run: {}", err);
+            return previous.map(<[_]>::to_vec).unwrap_or_default();
+        }
+    };
+
+    for line in diff_answers(previous, &answers) {
+        println!("{}", line);
+    }
+    answers
+}
+
+// The `part N: answer` lines a day's binary prints, as (part, answer)
+fn parse_answers(stdout: &str) -> Vec<(String, String)> {
+    stdout
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("part "))
+        .filter_map(|line| line.split_once(": "))
+        .map(|(part, answer)| (part.to_string(), answer.to_string()))
+        .collect()
+}
+
+// A line per part, saying whether its answer changed since the previous run
+fn diff_answers(
+    previous: Option<&[(String, String)]>,
+    answers: &[(String, String)],
+) -> Vec<String> {
+    answers
+        .iter()
+        .map(|(part, answer)| {
+            let before = previous.and_then(|previous| {
+                previous
+                    .iter()
+                    .find(|(previous_part, _)| previous_part == part)
+                    .map(|(_, answer)| answer)
+            });
+
+            match before {
+                _ if previous.is_none() => format!("part {}: {}", part, answer),
+                Some(before) if before == answer => {
+                    format!("part {}: {} (unchanged)", part, answer)
+                }
+                Some(before) => format!("part {}: {} (was {})", part, answer, before),
+                None => format!("part {}: {} (new)", part, answer),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(answers: &[(&str, &str)]) -> Vec<(String, String)> {
+        answers
+            .iter()
+            .map(|(part, answer)| (part.to_string(), answer.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("day: 09\n  part 1: 1696140818\n  part 2: -1152\n"),
+            answers(&[("1", "1696140818"), ("2", "-1152")])
+        );
+        assert_eq!(parse_answers(""), vec![]);
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let before = answers(&[("1", "6")]);
+        let after = answers(&[("1", "7"), ("2", "3")]);
+
+        assert_eq!(diff_answers(None, &before), vec!["part 1: 6"]);
+        assert_eq!(
+            diff_answers(Some(&before), &before),
+            vec!["part 1: 6 (unchanged)"]
+        );
+        assert_eq!(
+            diff_answers(Some(&before), &after),
+            vec!["part 1: 7 (was 6)", "part 2: 3 (new)"]
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_core::ParseError;
+use std::str::FromStr;
+
+/// A line of the input
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Thing {
+    /// The line's number
+    pub value: usize,
+}
+
+impl FromStr for Thing {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.parse().map_err(|_| ParseError::new(1, "a number"))?;
+
+        Ok(Thing { value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FIXTURES;
+    use aoc_core::Input;
+
+    #[test]
+    fn test_thing_from_str() {
+        assert_eq!(
+            FIXTURES.get_input::<Thing>(Input::Test(1)).unwrap()[0],
+            Thing { value: 1 }
+        );
+    }
+
+    #[test]
+    fn test_thing_from_str_error() {
+        assert_eq!("one".parse::<Thing>(), Err(ParseError::new(1, "a number")));
+    }
+}
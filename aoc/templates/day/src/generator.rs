@@ -0,0 +1,17 @@
+use aoc_core::Rng;
+
+// `size` lines of a number each
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let lines: Vec<String> = (0..size).map(|_| rng.range(0..100).to_string()).collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day{{DAY}};
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day{{DAY}}>(0..20, 50);
+    }
+}
@@ -0,0 +1,50 @@
+//! Day {{DAY_NUMBER}}
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{parse_lines, trace, Fixtures, ParseError, Rng, Solution};
+pub use data::*;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day{{DAY}};
+
+impl Solution for Day{{DAY}} {
+    const DAY: u8 = {{DAY_NUMBER}};
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Vec<Thing>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Thing>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_1(things: Vec<Thing>) -> usize {
+        part_1(things)
+    }
+
+    fn part_2(things: Vec<Thing>) -> usize {
+        part_2(things)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// The part 1 question
+pub fn part_1(things: Vec<Thing>) -> usize {
+    let sum = things.iter().map(|thing| thing.value).sum();
+
+    trace!(Summary, "{} things add up to {}", things.len(), sum);
+    sum
+}
+
+/// The part 2 question
+pub fn part_2(things: Vec<Thing>) -> usize {
+    things.len()
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::run_main(day_{{DAY}}::Day{{DAY}});
+}
@@ -0,0 +1,132 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering::Relaxed};
+
+// A global allocator that counts what goes through it, so `measure` can say how much something
+// allocated. Opt in by installing it:
+//
+//   #[global_allocator]
+//   static ALLOC: aoc_core::alloc::CountingAlloc = aoc_core::alloc::CountingAlloc;
+//
+// The counts are global, not per thread, so they include work a day hands to rayon, but also
+// anything else running at the same time: measure one thing at a time.
+pub struct CountingAlloc;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+
+// What happened on the heap while something ran
+#[derive(Default, Eq, PartialEq, Copy, Clone, Debug)]
+pub struct AllocStats {
+    // The most it had allocated at once, beyond what was already allocated when it started
+    pub peak_bytes: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+fn allocated(size: usize) {
+    let current = CURRENT.fetch_add(size as u64, Relaxed) + size as u64;
+    PEAK.fetch_max(current, Relaxed);
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    ALLOCATED.fetch_add(size as u64, Relaxed);
+}
+
+fn freed(size: usize) {
+    CURRENT.fetch_sub(size as u64, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            INSTALLED.store(true, Relaxed);
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            INSTALLED.store(true, Relaxed);
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        freed(layout.size());
+    }
+
+    // A realloc counts as an allocation of the new size (it may well copy), and a free of the old
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+// Whether `CountingAlloc` is the global allocator, i.e. whether `measure` means anything
+pub fn installed() -> bool {
+    INSTALLED.load(Relaxed)
+}
+
+// Runs `f`, counting its allocations. Without `CountingAlloc` installed, the stats are all zero.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    let start_current = CURRENT.load(Relaxed);
+    let start_allocations = ALLOCATIONS.load(Relaxed);
+    let start_allocated = ALLOCATED.load(Relaxed);
+    // Track this peak from here, and put back an outer `measure`'s if it was higher
+    let outer_peak = PEAK.swap(start_current, Relaxed);
+
+    let result = f();
+
+    let peak = PEAK.fetch_max(outer_peak, Relaxed);
+    let stats = AllocStats {
+        peak_bytes: peak.saturating_sub(start_current),
+        allocations: ALLOCATIONS.load(Relaxed) - start_allocations,
+        allocated_bytes: ALLOCATED.load(Relaxed) - start_allocated,
+    };
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[global_allocator]
+    static ALLOC: CountingAlloc = CountingAlloc;
+
+    // One test, since measuring at the same time as another test would muddle the peaks
+    #[test]
+    fn test_measure() {
+        // Other tests allocate at the same time, so these are lower bounds
+        let (_, stats) = measure(|| {
+            let big = black_box(vec![0u8; 1 << 20]);
+            drop(big);
+            black_box(vec![0u8; 1 << 10])
+        });
+
+        assert!(installed());
+        assert!(stats.allocations >= 2);
+        assert!(stats.allocated_bytes >= (1 << 20) + (1 << 10));
+        assert!(stats.peak_bytes >= 1 << 20);
+
+        let (inner, outer) = measure(|| {
+            let big = black_box(vec![0u8; 1 << 20]);
+            drop(big);
+            measure(|| black_box(vec![0u8; 1 << 10])).1
+        });
+
+        assert!(inner.allocated_bytes >= 1 << 10);
+        assert!(outer.allocated_bytes >= (1 << 20) + (1 << 10));
+        assert!(outer.peak_bytes >= 1 << 20);
+    }
+}
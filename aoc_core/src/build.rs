@@ -0,0 +1,196 @@
+use crate::Part;
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+// How many mutated inputs each day's generated `fuzz_parse_mutations` test tries
+const MUTATION_SEEDS: u64 = 2000;
+
+// Called from each day's build script. Finds `src/input/real.txt`, every `src/input/test_N.txt`
+// and the expected answers in `src/input/answers.toml`, then writes `$OUT_DIR/fixtures.rs` with the
+// day's `FIXTURES` static, a test per expected answer, one for the answers with mangled inputs and
+// a parser fuzzing test.
+// `aoc_core::fixtures!()` includes it.
+pub fn generate_fixtures() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let input_dir = Path::new(&manifest_dir).join("src").join("input");
+    println!("cargo:rerun-if-changed={}", input_dir.display());
+
+    let solution = solution_name(&env::var("CARGO_PKG_NAME").unwrap());
+    let source = fixtures_source(&input_dir, &solution).unwrap_or_else(|err| panic!("{}", err));
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("fixtures.rs"), source).unwrap();
+}
+
+// `day_05` => `Day05`
+pub fn solution_name(package_name: &str) -> String {
+    format!("Day{}", package_name.trim_start_matches("day_"))
+}
+
+pub fn fixtures_source(input_dir: &Path, solution: &str) -> Result<String, String> {
+    let read_dir =
+        fs::read_dir(input_dir).map_err(|e| format!("{}: {}", input_dir.display(), e))?;
+
+    let mut tests = read_dir
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let n = name
+                .strip_prefix("test_")?
+                .strip_suffix(".txt")?
+                .parse()
+                .ok()?;
+            Some((n, name))
+        })
+        .collect::<Vec<(usize, String)>>();
+    tests.sort();
+
+    let answers = match fs::read_to_string(input_dir.join("answers.toml")) {
+        Ok(toml) => parse_answers(&toml)?,
+        Err(_) => vec![],
+    };
+
+    for (n, part, _) in answers.iter() {
+        if !tests.iter().any(|(test_n, _)| test_n == n) {
+            return Err(format!(
+                "answers.toml has part {} of test_{}.txt, which doesn't exist",
+                part, n
+            ));
+        }
+    }
+
+    let path = |name: &str| format!("{:?}", input_dir.join(name).display().to_string());
+    let mut source = String::new();
+
+    writeln!(
+        source,
+        "/// The day's real and test inputs, and the expected answers for the test inputs"
+    )
+    .unwrap();
+    writeln!(
+        source,
+        "pub static FIXTURES: ::aoc_core::Fixtures = ::aoc_core::Fixtures {{"
+    )
+    .unwrap();
+    writeln!(source, "    real: include_str!({}),", path("real.txt")).unwrap();
+    writeln!(source, "    tests: &[").unwrap();
+    for (n, name) in tests.iter() {
+        writeln!(source, "        ({}, include_str!({})),", n, path(name)).unwrap();
+    }
+    writeln!(source, "    ],").unwrap();
+    writeln!(source, "    answers: &[").unwrap();
+    for (n, part, answer) in answers.iter() {
+        writeln!(
+            source,
+            "        ({}, ::aoc_core::Part::{:?}, {:?}),",
+            n, part, answer
+        )
+        .unwrap();
+    }
+    writeln!(source, "    ],").unwrap();
+    writeln!(source, "}};").unwrap();
+
+    for (n, part, answer) in answers.iter() {
+        writeln!(source, "\n#[test]").unwrap();
+        writeln!(source, "fn fixture_test_{}_part_{}() {{", n, part).unwrap();
+        writeln!(
+            source,
+            "    ::aoc_core::check_answer::<crate::{}>(::aoc_core::Input::Test({}), ::aoc_core::Part::{:?}, {:?});",
+            solution, n, part, answer
+        )
+        .unwrap();
+        writeln!(source, "}}").unwrap();
+    }
+
+    writeln!(source, "\n#[test]").unwrap();
+    writeln!(source, "fn mangled_fixture_answers() {{").unwrap();
+    writeln!(
+        source,
+        "    ::aoc_core::check_mangled_answers::<crate::{}>();",
+        solution
+    )
+    .unwrap();
+    writeln!(source, "}}").unwrap();
+
+    writeln!(source, "\n#[test]").unwrap();
+    writeln!(source, "fn fuzz_parse_mutations() {{").unwrap();
+    writeln!(
+        source,
+        "    ::aoc_core::check_parse_mutations::<crate::{}>(0..{});",
+        solution, MUTATION_SEEDS
+    )
+    .unwrap();
+    writeln!(source, "}}").unwrap();
+
+    Ok(source)
+}
+
+// Reads the little subset of TOML that answers.toml uses: a `[test_N]` table per test input, each
+// with `part_1`/`part_2` keys whose values are integers or quoted strings.
+//
+//   [test_1]
+//   part_1 = 142
+pub fn parse_answers(toml: &str) -> Result<Vec<(usize, Part, String)>, String> {
+    let mut answers = vec![];
+    let mut test = None;
+
+    for (idx, line) in toml.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        let err = |msg: &str| format!("answers.toml:{}: {}", idx + 1, msg);
+
+        if line.is_empty() {
+            continue;
+        } else if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let n = table
+                .trim()
+                .strip_prefix("test_")
+                .and_then(|n| n.parse().ok());
+            test = Some(n.ok_or_else(|| err("expected a [test_N] table"))?);
+        } else if let Some((key, value)) = line.split_once('=') {
+            let test = test.ok_or_else(|| err("expected a [test_N] table before any answers"))?;
+            let part = match key.trim() {
+                "part_1" => Part::One,
+                "part_2" => Part::Two,
+                _ => return Err(err("expected a part_1 or part_2 key")),
+            };
+            let value = value.trim();
+            let answer = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Some(quoted) => quoted.to_string(),
+                None if value.parse::<i128>().is_ok() => value.to_string(),
+                None => return Err(err("expected an integer or quoted string answer")),
+            };
+            answers.push((test, part, answer));
+        } else {
+            return Err(err("expected a [test_N] table or a `part_N = answer` key"));
+        }
+    }
+
+    Ok(answers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let toml = "# Day 10\n[test_1]\npart_1 = 8\n\n[test_2]\npart_2 = \"4\" # quoted\n";
+        assert_eq!(
+            parse_answers(toml),
+            Ok(vec![
+                (1, Part::One, "8".to_string()),
+                (2, Part::Two, "4".to_string())
+            ])
+        );
+
+        assert!(parse_answers("part_1 = 8").is_err());
+        assert!(parse_answers("[real]\npart_1 = 8").is_err());
+        assert!(parse_answers("[test_1]\npart_3 = 8").is_err());
+        assert!(parse_answers("[test_1]\npart_1 = eight").is_err());
+    }
+
+    #[test]
+    fn test_solution_name() {
+        assert_eq!(solution_name("day_05"), "Day05");
+    }
+}
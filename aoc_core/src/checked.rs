@@ -0,0 +1,152 @@
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::{Mutex, Once};
+use std::thread::{self, ThreadId};
+
+// Panics seen while something was in `catch`, and the thread they happened on. Days can run parts
+// of themselves on other threads (rayon re-raises those panics on the caller's thread), so `catch`
+// looks for its own thread's panic first, then one with the same message.
+static CAUGHT: Mutex<Vec<(ThreadId, Panic)>> = Mutex::new(vec![]);
+static CATCHING: Mutex<usize> = Mutex::new(0);
+static INSTALL_HOOK: Once = Once::new();
+
+// A panic, and where it happened
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Panic {
+    pub message: String,
+    // `file:line:column`
+    pub location: Option<String>,
+}
+
+impl Panic {
+    fn from_hook(info: &PanicHookInfo) -> Panic {
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()));
+
+        Panic {
+            message: payload_message(info.payload()),
+            location,
+        }
+    }
+
+    // Like `attempt to multiply with overflow`, from an arithmetic overflow check
+    pub fn is_overflow(&self) -> bool {
+        self.message.ends_with("with overflow")
+    }
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.is_overflow() {
+            "overflow"
+        } else {
+            "panic"
+        };
+        match &self.location {
+            Some(location) => write!(f, "{} at {}: {}", kind, location, self.message),
+            None => write!(f, "{}: {}", kind, self.message),
+        }
+    }
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string())
+}
+
+// Runs `f`, turning a panic into an error that says where it happened instead of printing it.
+// Panics outside of `catch` still go to the usual hook.
+pub fn catch<R>(f: impl FnOnce() -> R) -> Result<R, Panic> {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if *CATCHING.lock().unwrap_or_else(|err| err.into_inner()) == 0 {
+                return previous(info);
+            }
+            let mut caught = CAUGHT.lock().unwrap_or_else(|err| err.into_inner());
+            caught.push((thread::current().id(), Panic::from_hook(info)));
+        }));
+    });
+
+    *CATCHING.lock().unwrap_or_else(|err| err.into_inner()) += 1;
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+
+    let mut catching = CATCHING.lock().unwrap_or_else(|err| err.into_inner());
+    let mut caught = CAUGHT.lock().unwrap_or_else(|err| err.into_inner());
+    let result = result.map_err(|payload| {
+        let message = payload_message(&*payload);
+        let this_thread = thread::current().id();
+        let idx = caught
+            .iter()
+            .position(|(thread, _)| *thread == this_thread)
+            .or_else(|| {
+                caught
+                    .iter()
+                    .position(|(_, panic)| panic.message == message)
+            });
+
+        match idx {
+            Some(idx) => caught.remove(idx).1,
+            None => Panic {
+                message,
+                location: None,
+            },
+        }
+    });
+
+    *catching -= 1;
+    if *catching == 0 {
+        caught.clear();
+    }
+    result
+}
+
+// Whether this build panics on arithmetic overflow, which is what `--checked` relies on. Debug
+// builds do; release builds only with the `checked` profile (or `overflow-checks = true`).
+pub fn overflow_checks_enabled() -> bool {
+    catch(|| black_box(u8::MAX) + black_box(1)).is_err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| 1 + 1), Ok(2));
+
+        let overflow = catch(|| black_box(usize::MAX) * black_box(2)).unwrap_err();
+        assert!(overflow.is_overflow());
+        assert_eq!(overflow.message, "attempt to multiply with overflow");
+        assert!(overflow
+            .location
+            .as_ref()
+            .is_some_and(|l| l.starts_with("aoc_core/src/checked.rs:")));
+
+        let panic = catch(|| panic!("no {}", "luck")).unwrap_err();
+        assert!(!panic.is_overflow());
+        assert_eq!(panic.message, "no luck");
+
+        // From another thread, which re-raises it
+        let panic = catch(|| {
+            thread::spawn(|| black_box(0usize) - black_box(1))
+                .join()
+                .unwrap_or_else(|payload| panic::resume_unwind(payload))
+        })
+        .unwrap_err();
+        assert_eq!(panic.message, "attempt to subtract with overflow");
+        assert!(panic.location.is_some());
+    }
+
+    #[test]
+    fn test_overflow_checks_enabled() {
+        // Tests build with debug assertions, and so with overflow checks
+        assert!(overflow_checks_enabled());
+    }
+}
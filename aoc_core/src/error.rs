@@ -0,0 +1,124 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+// Where and why some input couldn't be parsed. A parser fills in what it knows (the column, and
+// what it expected to find there); `parse_lines` adds the line, and the caller adds the input name.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ParseError {
+    pub input: Option<String>,
+    pub line: Option<usize>,
+    pub text: Option<String>,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    // `column` is 1-based
+    pub fn new(column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            input: None,
+            line: None,
+            text: None,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    // An error at the start of `token`, which must be a slice of `line`
+    pub fn at(line: &str, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let within_line = offset
+            .checked_add(token.len())
+            .is_some_and(|end| end <= line.len());
+        let column = if within_line { offset + 1 } else { 1 };
+        ParseError::new(column, expected)
+    }
+
+    // `line` is 1-based
+    pub fn with_line(mut self, line: usize, text: &str) -> ParseError {
+        self.line = Some(line);
+        self.text = Some(text.to_string());
+        self
+    }
+
+    pub fn with_input(mut self, input: impl Display) -> ParseError {
+        self.input = Some(input.to_string());
+        self
+    }
+}
+
+impl From<Infallible> for ParseError {
+    fn from(infallible: Infallible) -> Self {
+        match infallible {}
+    }
+}
+
+// Renders as a compiler-style diagnostic:
+//
+//   test_1.txt:2:6: expected a card (A, K, Q, J, T or 2-9)
+//     |
+//   2 | KK6X7 220
+//     |    ^
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let input = self.input.as_deref().unwrap_or("input");
+
+        match self.line {
+            Some(line) => write!(f, "{}:{}:{}: ", input, line, self.column)?,
+            None => write!(f, "{}:?:{}: ", input, self.column)?,
+        }
+        write!(f, "expected {}", self.expected)?;
+
+        if let (Some(line), Some(text)) = (self.line, &self.text) {
+            let gutter = " ".repeat(line.to_string().len());
+            let pointer = " ".repeat(self.column.saturating_sub(1));
+            write!(
+                f,
+                "\n{} |\n{} | {}\n{} | {}^",
+                gutter, line, text, gutter, pointer
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_at() {
+        let line = "Card 1: 41 x8";
+        let error = ParseError::at(line, &line[11..], "a number");
+        assert_eq!(error.column, 12);
+
+        let error = ParseError::at(line, "elsewhere", "a number");
+        assert_eq!(error.column, 1);
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let error = ParseError::new(4, "a card (A, K, Q, J, T or 2-9)")
+            .with_line(12, "KK6X7 220")
+            .with_input("test_1.txt");
+        assert_eq!(
+            error.to_string(),
+            [
+                "test_1.txt:12:4: expected a card (A, K, Q, J, T or 2-9)",
+                "   |",
+                "12 | KK6X7 220",
+                "   |    ^",
+            ]
+            .join("\n")
+        );
+
+        assert_eq!(
+            ParseError::new(1, "a number").to_string(),
+            "input:?:1: expected a number"
+        );
+    }
+}
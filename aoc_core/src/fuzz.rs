@@ -0,0 +1,145 @@
+use crate::{Rng, Solution};
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+
+// Chars that tend to mean something to at least one day's parser
+const INTERESTING_CHARS: &[char] = &[
+    '0', '1', '9', '-', '+', ' ', '\n', '\r', '\t', ':', ';', ',', '.', '#', '?', '=', '(', ')',
+    'A', 'L', 'R', 'S', 'Z', '|', 'F', '7', 'J', 'é', '\u{0}',
+];
+const INTERESTING_STRINGS: &[&str] = &[
+    "",
+    "0",
+    "-1",
+    "99999999999999999999999",
+    "18446744073709551615",
+    "\n\n",
+    "Card 1:",
+    "Game 1:",
+    " = (",
+    "map:",
+];
+
+// A few random edits to `input`: deleting, duplicating or swapping chunks, inserting odd chars or
+// numbers, or cutting it short
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+
+    for _ in 0..rng.range(1..5) {
+        let at = rng.range(0..chars.len() + 1);
+        let chunk = at..(at + rng.range(1..8)).min(chars.len());
+
+        match rng.range(0..7) {
+            0 => {
+                chars.drain(chunk);
+            }
+            1 => {
+                let copy: Vec<char> = chars[chunk].to_vec();
+                chars.splice(at..at, copy);
+            }
+            2 => chars.insert(at, *rng.choose(INTERESTING_CHARS)),
+            3 if at < chars.len() => chars[at] = *rng.choose(INTERESTING_CHARS),
+            4 => {
+                let string = rng.choose(INTERESTING_STRINGS);
+                chars.splice(at..at, string.chars());
+            }
+            5 => chars.truncate(at),
+            _ => {
+                let from = rng.range(0..chars.len() + 1);
+                let copy: Vec<char> =
+                    chars[from..(from + rng.range(1..20)).min(chars.len())].to_vec();
+                chars.splice(chunk, copy);
+            }
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+// Used by the test generated for every day: mutates the day's embedded and generated inputs, and
+// checks that parsing each one returns (an answer or an error) rather than panicking
+pub fn check_parse_mutations<S: Solution>(seeds: Range<u64>) {
+    let mut corpus: Vec<String> = S::FIXTURES
+        .tests
+        .iter()
+        .map(|(_, text)| text.to_string())
+        .collect();
+    corpus.push(S::FIXTURES.real.to_string());
+    corpus.extend(S::generate(&mut Rng::new(0), 5));
+
+    for seed in seeds {
+        let mut rng = Rng::new(seed);
+        let original = rng.choose(&corpus);
+        let input = mutate(&mut rng, original);
+
+        if panic::catch_unwind(AssertUnwindSafe(|| S::parse(&input))).is_err() {
+            panic!(
+                "day {} parser panicked on mutation seed {}: {:?}",
+                S::DAY,
+                seed,
+                input
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fixtures, ParseError};
+
+    // Slices at a fixed offset, like the parsers this is here to catch
+    struct Fragile;
+
+    static FRAGILE_FIXTURES: Fixtures = Fixtures {
+        real: "abc: 123",
+        tests: &[],
+        answers: &[],
+    };
+
+    impl Solution for Fragile {
+        const DAY: u8 = 0;
+        const FIXTURES: &'static Fixtures = &FRAGILE_FIXTURES;
+
+        type Parsed = usize;
+        type Answer = usize;
+
+        fn parse(input: &str) -> Result<usize, ParseError> {
+            input[5..]
+                .parse()
+                .map_err(|_| ParseError::new(6, "a number"))
+        }
+
+        fn part_1(n: usize) -> usize {
+            n
+        }
+
+        fn part_2(n: usize) -> usize {
+            n
+        }
+    }
+
+    #[test]
+    fn test_mutate() {
+        let input = "Time: 7 15 30\nDistance: 9 40 200";
+        let mutated: Vec<String> = (0..50)
+            .map(|seed| mutate(&mut Rng::new(seed), input))
+            .collect();
+
+        assert!(mutated.iter().filter(|m| *m != input).count() > 40);
+        assert_eq!(
+            mutated,
+            (0..50)
+                .map(|seed| mutate(&mut Rng::new(seed), input))
+                .collect::<Vec<_>>()
+        );
+        // Empty inputs get mutated too
+        assert!((0..50).any(|seed| !mutate(&mut Rng::new(seed), "").is_empty()));
+    }
+
+    #[test]
+    #[should_panic(expected = "day 0 parser panicked on mutation seed")]
+    fn test_check_parse_mutations() {
+        check_parse_mutations::<Fragile>(0..100);
+    }
+}
@@ -0,0 +1,358 @@
+use crate::ParseError;
+use std::fmt::{Debug, Formatter};
+use std::ops::{Index, IndexMut};
+
+// A position in a grid. Rows count down from the top, columns across from the left.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Pos {
+        Pos { row, col }
+    }
+
+    // `None` if it would go above or left of the grid; the grid itself checks the other two edges
+    pub fn offset(&self, rows: isize, cols: isize) -> Option<Pos> {
+        Some(Pos {
+            row: self.row.checked_add_signed(rows)?,
+            col: self.col.checked_add_signed(cols)?,
+        })
+    }
+
+    pub fn step(&self, dir: Dir) -> Option<Pos> {
+        let (rows, cols) = dir.offset();
+        self.offset(rows, cols)
+    }
+}
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    pub fn opposite(&self) -> Dir {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+
+    // As (rows, cols)
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Dir::Up => (-1, 0),
+            Dir::Down => (1, 0),
+            Dir::Left => (0, -1),
+            Dir::Right => (0, 1),
+        }
+    }
+}
+
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+// A dense, rectangular grid, stored row by row
+#[derive(Eq, PartialEq, Hash, Clone)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    // Parses one row per line, one cell per char. `cell` returns `None` for chars that aren't
+    // valid cells, which are reported as `expected`.
+    pub fn parse<S: AsRef<str>>(
+        lines: &[S],
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let rows = lines.len();
+        let cols = lines
+            .first()
+            .map_or(0, |line| line.as_ref().chars().count());
+        let mut cells = Vec::with_capacity(rows * cols);
+
+        for (row, line) in lines.iter().map(AsRef::as_ref).enumerate() {
+            let error =
+                |column, expected: &str| ParseError::new(column, expected).with_line(row + 1, line);
+
+            let mut len = 0;
+            for (col, c) in line.chars().enumerate() {
+                if col == cols {
+                    return Err(error(col + 1, &format!("a row of {} cells", cols)));
+                }
+                cells.push(cell(c).ok_or_else(|| error(col + 1, expected))?);
+                len += 1;
+            }
+
+            if len < cols {
+                return Err(error(len + 1, &format!("a row of {} cells", cols)));
+            }
+        }
+
+        Ok(Grid { cells, rows, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.rows && pos.col < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.cols + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.row * self.cols + pos.col])
+    }
+
+    // Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| Pos { row, col }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // The position one step from `pos`, if it's still inside the grid
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        pos.step(dir).filter(|pos| self.contains(*pos))
+    }
+
+    // Up, down, left and right of `pos`, within the grid
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    // Including diagonals
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |(rows, cols)| pos.offset(rows, cols))
+            .filter(|pos| self.contains(*pos))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.cols, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(&mut f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    // Builds a `rows` x `cols` grid where each cell comes from `self[source(pos)]`
+    fn rearranged(&self, rows: usize, cols: usize, source: impl Fn(Pos) -> Pos) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| Pos { row, col }))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Grid { cells, rows, cols }
+    }
+
+    // Rows become columns
+    pub fn transposed(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearranged(self.cols, self.rows, |pos| Pos::new(pos.col, pos.row))
+    }
+
+    // Rotated 90 degrees clockwise
+    pub fn rotated(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let rows = self.rows;
+        self.rearranged(self.cols, self.rows, |pos| {
+            Pos::new(rows - 1 - pos.col, pos.row)
+        })
+    }
+
+    // Mirrored left to right
+    pub fn flipped_horizontally(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cols = self.cols;
+        self.rearranged(self.rows, self.cols, |pos| {
+            Pos::new(pos.row, cols - 1 - pos.col)
+        })
+    }
+
+    // Mirrored top to bottom
+    pub fn flipped_vertically(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let rows = self.rows;
+        self.rearranged(self.rows, self.cols, |pos| {
+            Pos::new(rows - 1 - pos.row, pos.col)
+        })
+    }
+}
+
+impl Grid<char> {
+    // Any char is a valid cell
+    pub fn from_lines<S: AsRef<str>>(lines: &[S]) -> Result<Grid<char>, ParseError> {
+        Grid::parse(lines, "a cell", Some)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} out of bounds for a {}x{} grid",
+                pos, self.rows, self.cols
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} out of bounds for a {}x{} grid", pos, rows, cols))
+    }
+}
+
+// One row per line, which reads much better than the flat cells
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter_rows()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(lines: &[&str]) -> Grid<char> {
+        Grid::from_lines(lines).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let g = grid(&["abc", "def"]);
+        assert_eq!((g.rows(), g.cols()), (2, 3));
+        assert_eq!(g[Pos::new(1, 0)], 'd');
+        assert_eq!(g.get(Pos::new(2, 0)), None);
+        assert_eq!(g.get(Pos::new(0, 3)), None);
+
+        let digits = Grid::parse(&["12", "3x"], "a digit", |c| c.to_digit(10));
+        assert_eq!(
+            digits,
+            Err(ParseError::new(2, "a digit").with_line(2, "3x"))
+        );
+
+        let ragged = Grid::from_lines(&["abc", "de"]);
+        assert_eq!(
+            ragged,
+            Err(ParseError::new(3, "a row of 3 cells").with_line(2, "de"))
+        );
+        let ragged = Grid::from_lines(&["ab", "cde"]);
+        assert_eq!(
+            ragged,
+            Err(ParseError::new(3, "a row of 2 cells").with_line(2, "cde"))
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid(&["abc", "def", "ghi"]);
+        let chars = |positions: Vec<Pos>| positions.into_iter().map(|p| g[p]).collect::<String>();
+
+        assert_eq!(chars(g.neighbours_4(Pos::new(1, 1)).collect()), "bhdf");
+        assert_eq!(chars(g.neighbours_4(Pos::new(0, 0)).collect()), "db");
+        assert_eq!(chars(g.neighbours_8(Pos::new(1, 1)).collect()), "abcdfghi");
+        assert_eq!(chars(g.neighbours_8(Pos::new(2, 2)).collect()), "efh");
+        assert_eq!(g.step(Pos::new(0, 2), Dir::Right), None);
+        assert_eq!(g.step(Pos::new(0, 2), Dir::Down), Some(Pos::new(1, 2)));
+    }
+
+    #[test]
+    fn test_views() {
+        let g = grid(&["abc", "def"]);
+        assert_eq!(g.row(1), ['d', 'e', 'f']);
+        assert_eq!(g.column(2).collect::<String>(), "cf");
+        assert_eq!(g.iter_rows().count(), 2);
+        assert_eq!(
+            g.iter().find(|(_, c)| **c == 'e').map(|(pos, _)| pos),
+            Some(Pos::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_transforms() {
+        let g = grid(&["abc", "def"]);
+        assert_eq!(g.transposed(), grid(&["ad", "be", "cf"]));
+        assert_eq!(g.rotated(), grid(&["da", "eb", "fc"]));
+        assert_eq!(g.flipped_horizontally(), grid(&["cba", "fed"]));
+        assert_eq!(g.flipped_vertically(), grid(&["def", "abc"]));
+        assert_eq!(g.rotated().rotated().rotated().rotated(), g);
+        assert_eq!(g.map(|c| c.to_ascii_uppercase()), grid(&["ABC", "DEF"]));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{normalize, ParseError, Part};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::{env, fs, io};
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Input {
+    Real,
+    Test(usize),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Input {
+    // Parses `--input <path>` (or `--input -` for stdin) from the command line. Without the flag,
+    // the embedded real input is used.
+    pub fn from_args() -> Result<Input, String> {
+        Input::parse_args(env::args().skip(1))
+    }
+
+    pub fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Input, String> {
+        let mut input = Input::Real;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => match args.next() {
+                    Some(arg) => input = Input::from_arg(&arg),
+                    None => return Err("--input requires a path, or - for stdin".to_string()),
+                },
+                _ => return Err(format!("Unknown argument: {}", arg)),
+            }
+        }
+
+        Ok(input)
+    }
+
+    // The value of an `--input` flag: `-` means stdin, anything else is a path
+    pub fn from_arg(arg: &str) -> Input {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(path.into()),
+        }
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::Real => write!(f, "real.txt"),
+            Input::Test(n) => write!(f, "test_{}.txt", n),
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+// The puzzle inputs embedded into a day's binary, along with the expected answers for the test
+// inputs. Each day's are generated at build time; see `build::generate_fixtures`.
+pub struct Fixtures {
+    pub real: &'static str,
+    pub tests: &'static [(usize, &'static str)],
+    pub answers: &'static [(usize, Part, &'static str)],
+}
+
+impl Fixtures {
+    pub fn text(&self, input: &Input) -> io::Result<Cow<'static, str>> {
+        match input {
+            Input::Real => Ok(self.real.into()),
+            Input::Test(n) => self
+                .tests
+                .iter()
+                .find(|(test_n, _)| test_n == n)
+                .map(|(_, text)| (*text).into())
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::NotFound, format!("No test_{}.txt input", n))
+                }),
+            Input::File(path) => fs::read_to_string(path).map(Cow::from),
+            Input::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(text.into())
+            }
+        }
+    }
+
+    // Parses each line of an embedded input. Panics if `input` isn't one of the embedded ones.
+    pub fn get_input<ParseAs>(&self, input: Input) -> Result<Vec<ParseAs>, ParseError>
+    where
+        ParseAs: FromStr,
+        <ParseAs as FromStr>::Err: Into<ParseError>,
+    {
+        let text = self.text(&input).unwrap();
+        parse_lines(&text).map_err(|err| err.with_input(input))
+    }
+}
+
+// Parses each line of the input, once it's normalized (see `normalize`). Errors have the line's
+// number in the original input.
+pub fn parse_lines<ParseAs>(input: &str) -> Result<Vec<ParseAs>, ParseError>
+where
+    ParseAs: FromStr,
+    <ParseAs as FromStr>::Err: Into<ParseError>,
+{
+    let normalized = normalize(input);
+    normalized
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(idx, s)| {
+            s.parse()
+                .map_err(|err: ParseAs::Err| err.into().with_line(normalized.first_line + idx, s))
+        })
+        .collect()
+}
+
+// For days that parse the input as a whole: hands all of its lines, once it's normalized, to
+// `parse`, which numbers them from 1. Errors have the line's number in the original input.
+pub fn parse_whole<T>(
+    input: &str,
+    parse: impl FnOnce(Vec<String>) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let normalized = normalize(input);
+    let lines = normalized
+        .lines
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+    parse(lines).map_err(|mut err| {
+        err.line = err.line.map(|line| line + normalized.first_line - 1);
+        err
+    })
+}
+
+// Declares the `FIXTURES` static (and the expected answer tests) generated by the day's build
+// script, which must call `aoc_core::build::generate_fixtures()`.
+#[macro_export]
+macro_rules! fixtures {
+    () => {
+        include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(PartialEq, Debug)]
+    struct Num(usize);
+
+    impl FromStr for Num {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.parse()
+                .map(Num)
+                .map_err(|_| ParseError::new(1, "a number"))
+        }
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines::<String>("a b\n\nc\n"),
+            Ok(vec!["a b".to_string(), "".to_string(), "c".to_string()])
+        );
+        assert_eq!(parse_lines("1\n2\n"), Ok(vec![Num(1), Num(2)]));
+        assert_eq!(
+            parse_lines::<Num>("1\n2\nthree\n"),
+            Err(ParseError::new(1, "a number").with_line(3, "three"))
+        );
+        assert_eq!(
+            parse_lines::<String>("\u{feff}  a\r\nb\t\r\n\r\n"),
+            Ok(vec!["  a".to_string(), "b".to_string()])
+        );
+        assert_eq!(
+            parse_lines::<Num>("\n\n1\nx\n"),
+            Err(ParseError::new(1, "a number").with_line(4, "x"))
+        );
+    }
+
+    #[test]
+    fn test_parse_whole() {
+        let second_is_a = |lines: Vec<String>| match lines.get(1) {
+            Some(line) if line == "a" => Ok(lines.len()),
+            line => Err(ParseError::new(1, "a").with_line(2, line.map_or("", String::as_str))),
+        };
+        assert_eq!(parse_whole("x\na\n", second_is_a), Ok(2));
+        assert_eq!(parse_whole("\r\n\nx\na\n\n", second_is_a), Ok(2));
+        assert_eq!(
+            parse_whole("\n\nx\nb\n", second_is_a),
+            Err(ParseError::new(1, "a").with_line(4, "b"))
+        );
+    }
+
+    #[test]
+    fn test_fixtures_text() {
+        let fixtures = Fixtures {
+            real: "real",
+            tests: &[(1, "one"), (3, "three")],
+            answers: &[],
+        };
+        assert_eq!(fixtures.text(&Input::Real).unwrap(), "real");
+        assert_eq!(fixtures.text(&Input::Test(3)).unwrap(), "three");
+        assert!(fixtures.text(&Input::Test(2)).is_err());
+    }
+
+    #[test]
+    fn test_fixtures_get_input() {
+        let fixtures = Fixtures {
+            real: "1\n2",
+            tests: &[(1, "1\nx")],
+            answers: &[],
+        };
+        assert_eq!(fixtures.get_input(Input::Real), Ok(vec![Num(1), Num(2)]));
+        assert_eq!(
+            fixtures.get_input::<Num>(Input::Test(1)).unwrap_err().input,
+            Some("test_1.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn test_input_parse_args() {
+        let parse = |args: &[&str]| Input::parse_args(args.iter().map(|a| a.to_string()));
+        assert_eq!(parse(&[]), Ok(Input::Real));
+        assert_eq!(parse(&["--input", "-"]), Ok(Input::Stdin));
+        assert_eq!(
+            parse(&["--input", "mine.txt"]),
+            Ok(Input::File("mine.txt".into()))
+        );
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--nope"]).is_err());
+    }
+}
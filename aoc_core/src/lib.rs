@@ -0,0 +1,23 @@
+pub mod alloc;
+pub mod build;
+pub mod checked;
+mod error;
+mod fuzz;
+mod grid;
+mod input;
+mod normalize;
+mod oracle;
+mod render;
+mod rng;
+mod solution;
+pub mod trace;
+
+pub use error::*;
+pub use fuzz::*;
+pub use grid::*;
+pub use input::*;
+pub use normalize::*;
+pub use oracle::*;
+pub use render::*;
+pub use rng::*;
+pub use solution::*;
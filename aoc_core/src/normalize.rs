@@ -0,0 +1,184 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+// Something `normalize` had to fix
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug)]
+pub enum Change {
+    ByteOrderMark,
+    CrlfLineEndings,
+    TrailingWhitespace,
+    Tabs,
+    BlankLines,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Change::ByteOrderMark => "removed a byte order mark",
+            Change::CrlfLineEndings => "converted CRLF line endings",
+            Change::TrailingWhitespace => "removed trailing whitespace",
+            Change::Tabs => "replaced tabs with spaces",
+            Change::BlankLines => "removed blank lines at the start or end",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+// An input's lines, as the days expect them
+#[derive(Eq, PartialEq, Debug)]
+pub struct Normalized<'a> {
+    pub lines: Vec<Cow<'a, str>>,
+    // The line number of the first line in the original text, which is more than 1 if there were
+    // blank lines before it
+    pub first_line: usize,
+    pub changes: Vec<Change>,
+}
+
+impl Normalized<'_> {
+    // Like `real.txt: removed a byte order mark, converted CRLF line endings`, if anything changed
+    pub fn warning(&self, input: impl Display) -> Option<String> {
+        if self.changes.is_empty() {
+            return None;
+        }
+
+        let changes = self.changes.iter().map(Change::to_string);
+        Some(format!(
+            "{}: {}",
+            input,
+            changes.collect::<Vec<_>>().join(", ")
+        ))
+    }
+}
+
+// Splits an input into lines, fixing what editors and browsers do to them: a UTF-8 byte order
+// mark, CRLF line endings, trailing spaces or tabs, tabs (each becomes a space) and blank lines
+// before or after the input (one final newline is expected, and isn't a change). Leading spaces are
+// kept. An empty input is a single empty line.
+pub fn normalize(input: &str) -> Normalized<'_> {
+    let mut changes = vec![];
+    let mut change = |c| {
+        if !changes.contains(&c) {
+            changes.push(c);
+        }
+    };
+
+    let text = match input.strip_prefix('\u{feff}') {
+        Some(text) => {
+            change(Change::ByteOrderMark);
+            text
+        }
+        None => input,
+    };
+
+    let mut lines = text
+        .split('\n')
+        .map(|line| {
+            let mut line = Cow::from(line);
+            if let Some(stripped) = line.strip_suffix('\r') {
+                change(Change::CrlfLineEndings);
+                line = stripped.to_string().into();
+            }
+            let trimmed = line.trim_end_matches([' ', '\t']);
+            if trimmed.len() < line.len() {
+                change(Change::TrailingWhitespace);
+                line = trimmed.to_string().into();
+            }
+            if line.contains('\t') {
+                change(Change::Tabs);
+                line = line.replace('\t', " ").into();
+            }
+            line
+        })
+        .collect::<Vec<_>>();
+
+    // The final newline leaves one empty line at the end, which is fine
+    if text.ends_with('\n') {
+        lines.pop();
+    }
+    let trailing = lines.iter().rev().take_while(|l| l.is_empty()).count();
+    let leading = lines.iter().take_while(|l| l.is_empty()).count();
+    if leading == lines.len() {
+        return Normalized {
+            lines: vec!["".into()],
+            first_line: 1,
+            changes,
+        };
+    }
+    if leading + trailing > 0 {
+        change(Change::BlankLines);
+    }
+    lines.truncate(lines.len() - trailing);
+    lines.drain(..leading);
+
+    changes.sort();
+    Normalized {
+        lines,
+        first_line: leading + 1,
+        changes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(input: &str) -> Vec<String> {
+        normalize(input)
+            .lines
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    fn changes(input: &str) -> Vec<Change> {
+        normalize(input).changes
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(lines("a\nb\n"), ["a", "b"]);
+        assert_eq!(changes("a\nb\n"), []);
+        assert_eq!(lines("a\n\nb"), ["a", "", "b"]);
+        assert_eq!(changes("a\n\nb"), []);
+        assert_eq!(lines("  a\n  b\n"), ["  a", "  b"]);
+        assert_eq!(changes("  a\n  b\n"), []);
+
+        assert_eq!(lines("\u{feff}a\r\nb\r\n"), ["a", "b"]);
+        assert_eq!(
+            changes("\u{feff}a\r\nb\r\n"),
+            [Change::ByteOrderMark, Change::CrlfLineEndings]
+        );
+        assert_eq!(lines("a \t\nb\t \n"), ["a", "b"]);
+        assert_eq!(changes("a \t\nb\t \n"), [Change::TrailingWhitespace]);
+        assert_eq!(lines("Time:\t7\t15\n"), ["Time: 7 15"]);
+        assert_eq!(changes("Time:\t7\t15\n"), [Change::Tabs]);
+        assert_eq!(lines("\n\na\nb\n\n\n"), ["a", "b"]);
+        assert_eq!(changes("\n\na\nb\n\n\n"), [Change::BlankLines]);
+    }
+
+    #[test]
+    fn test_normalize_empty() {
+        for input in ["", "\n", "\r\n"] {
+            assert_eq!(lines(input), [""]);
+        }
+        assert_eq!(changes(""), []);
+        assert_eq!(changes("\n"), []);
+        assert_eq!(changes("\r\n"), [Change::CrlfLineEndings]);
+    }
+
+    #[test]
+    fn test_first_line() {
+        assert_eq!(normalize("a\n").first_line, 1);
+        assert_eq!(normalize("\n\r\n  \na\n").first_line, 4);
+        assert_eq!(normalize("\n\n").first_line, 1);
+    }
+
+    #[test]
+    fn test_warning() {
+        assert_eq!(normalize("a\n").warning("real.txt"), None);
+        assert_eq!(
+            normalize("a \r\n").warning("real.txt"),
+            Some("real.txt: converted CRLF line endings, removed trailing whitespace".to_string())
+        );
+    }
+}
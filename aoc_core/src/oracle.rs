@@ -0,0 +1,224 @@
+use crate::Rng;
+use std::fmt::Debug;
+use std::ops::Range;
+
+// Gives up shrinking after this many steps, and reports whatever it has
+const MAX_SHRINK_STEPS: usize = 10_000;
+
+// Something that can be made simpler, to cut a failing input down to the part that matters
+pub trait Shrink: Sized {
+    // Strictly simpler versions of `self`, the most promising first. Empty when it's as simple as
+    // it gets.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+impl Shrink for usize {
+    fn shrink(&self) -> Vec<usize> {
+        let mut simpler = vec![0, self / 2, self.saturating_sub(1)];
+        simpler.dedup();
+        simpler.retain(|n| n < self);
+        simpler
+    }
+}
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<bool> {
+        if *self {
+            vec![false]
+        } else {
+            vec![]
+        }
+    }
+}
+
+// Shorter vectors first (each half, then each single element removed), then simpler elements
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Vec<T>> {
+        let mut simpler = vec![];
+
+        if self.len() > 1 {
+            let half = self.len() / 2;
+            simpler.push(self[half..].to_vec());
+            simpler.push(self[..half].to_vec());
+        }
+        for idx in 0..self.len() {
+            let mut shorter = self.clone();
+            shorter.remove(idx);
+            simpler.push(shorter);
+        }
+        for (idx, item) in self.iter().enumerate() {
+            for item in item.shrink() {
+                let mut simpler_item = self.clone();
+                simpler_item[idx] = item;
+                simpler.push(simpler_item);
+            }
+        }
+
+        simpler
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<(A, B)> {
+        let (a, b) = self;
+        let mut simpler: Vec<_> = a.shrink().into_iter().map(|a| (a, b.clone())).collect();
+        simpler.extend(b.shrink().into_iter().map(|b| (a.clone(), b)));
+        simpler
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone, C: Shrink + Clone> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<(A, B, C)> {
+        let (a, b, c) = self;
+        let mut simpler: Vec<_> = a
+            .shrink()
+            .into_iter()
+            .map(|a| (a, b.clone(), c.clone()))
+            .collect();
+        simpler.extend(b.shrink().into_iter().map(|b| (a.clone(), b, c.clone())));
+        simpler.extend(c.shrink().into_iter().map(|c| (a.clone(), b.clone(), c)));
+        simpler
+    }
+}
+
+// An input the two implementations disagree on, shrunk as far as it would go
+#[derive(Eq, PartialEq, Debug)]
+pub struct Counterexample<T, A> {
+    pub seed: u64,
+    pub input: T,
+    pub expected: A,
+    pub actual: A,
+    pub shrink_steps: usize,
+}
+
+// Runs `fast` and the slow but obviously right `reference` on an input generated from each seed.
+// Returns the first disagreement, shrunk to the simplest input that still disagrees.
+pub fn find_counterexample<T, A>(
+    seeds: Range<u64>,
+    generate: impl Fn(&mut Rng) -> T,
+    reference: impl Fn(&T) -> A,
+    fast: impl Fn(&T) -> A,
+) -> Option<Counterexample<T, A>>
+where
+    T: Shrink,
+    A: PartialEq,
+{
+    let disagreement = |input: &T| {
+        let (expected, actual) = (reference(input), fast(input));
+        (expected != actual).then_some((expected, actual))
+    };
+
+    for seed in seeds {
+        let mut input = generate(&mut Rng::new(seed));
+        let Some(mut answers) = disagreement(&input) else {
+            continue;
+        };
+
+        let mut shrink_steps = 0;
+        while shrink_steps < MAX_SHRINK_STEPS {
+            let simpler = input
+                .shrink()
+                .into_iter()
+                .find_map(|simpler| disagreement(&simpler).map(|answers| (simpler, answers)));
+            let Some((simpler, simpler_answers)) = simpler else {
+                break;
+            };
+
+            (input, answers) = (simpler, simpler_answers);
+            shrink_steps += 1;
+        }
+
+        let (expected, actual) = answers;
+        return Some(Counterexample {
+            seed,
+            input,
+            expected,
+            actual,
+            shrink_steps,
+        });
+    }
+
+    None
+}
+
+// For tests: panics with the shrunk counterexample if `fast` and `reference` ever disagree
+pub fn check_oracle<T, A>(
+    seeds: Range<u64>,
+    generate: impl Fn(&mut Rng) -> T,
+    reference: impl Fn(&T) -> A,
+    fast: impl Fn(&T) -> A,
+) where
+    T: Shrink + Debug,
+    A: PartialEq + Debug,
+{
+    if let Some(counterexample) = find_counterexample(seeds, generate, reference, fast) {
+        panic!(
+            "seed {}: expected {:?} but got {:?} for {:?} (shrunk in {} steps)",
+            counterexample.seed,
+            counterexample.expected,
+            counterexample.actual,
+            counterexample.input,
+            counterexample.shrink_steps
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(rng: &mut Rng) -> Vec<usize> {
+        (0..rng.range(0..20)).map(|_| rng.range(0..100)).collect()
+    }
+
+    fn sum(numbers: &[usize]) -> usize {
+        numbers.iter().sum()
+    }
+
+    // Forgets anything over 40
+    fn buggy_sum(numbers: &[usize]) -> usize {
+        numbers.iter().filter(|n| **n <= 40).sum()
+    }
+
+    #[test]
+    fn test_shrink() {
+        assert_eq!(10usize.shrink(), vec![0, 5, 9]);
+        assert_eq!(1usize.shrink(), vec![0]);
+        assert_eq!(0usize.shrink(), vec![]);
+        assert_eq!(
+            vec![1usize, 2].shrink(),
+            vec![
+                vec![2],
+                vec![1],
+                vec![2],
+                vec![1],
+                vec![0, 2],
+                vec![1, 0],
+                vec![1, 1]
+            ]
+        );
+        assert_eq!((true, 1usize).shrink(), vec![(false, 1), (true, 0)]);
+    }
+
+    #[test]
+    fn test_find_counterexample() {
+        let sum = |numbers: &Vec<usize>| sum(numbers);
+        assert_eq!(find_counterexample(0..50, numbers, sum, sum), None);
+
+        let buggy = |numbers: &Vec<usize>| buggy_sum(numbers);
+        let counterexample = find_counterexample(0..50, numbers, sum, buggy).unwrap();
+        assert_eq!(counterexample.input, vec![41]);
+        assert_eq!((counterexample.expected, counterexample.actual), (41, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 41 but got 0 for [41]")]
+    fn test_check_oracle() {
+        check_oracle(
+            0..50,
+            numbers,
+            |numbers: &Vec<usize>| sum(numbers),
+            |numbers: &Vec<usize>| buggy_sum(numbers),
+        );
+    }
+}
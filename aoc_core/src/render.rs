@@ -0,0 +1,229 @@
+use crate::{Grid, Pos};
+use std::fmt::Write;
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub enum Highlight {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+}
+
+impl Highlight {
+    fn ansi(&self) -> &'static str {
+        match self {
+            Highlight::Red => "\x1b[1;31m",
+            Highlight::Green => "\x1b[1;32m",
+            Highlight::Yellow => "\x1b[1;33m",
+            Highlight::Blue => "\x1b[1;34m",
+            Highlight::Magenta => "\x1b[1;35m",
+        }
+    }
+
+    fn rgb(&self) -> [u8; 3] {
+        match self {
+            Highlight::Red => [220, 50, 47],
+            Highlight::Green => [80, 200, 70],
+            Highlight::Yellow => [240, 200, 40],
+            Highlight::Blue => [60, 120, 230],
+            Highlight::Magenta => [200, 60, 200],
+        }
+    }
+}
+
+const BACKGROUND: [u8; 3] = [24, 24, 24];
+const FOREGROUND: [u8; 3] = [110, 110, 110];
+
+// A snapshot of some grid-shaped state, one char per cell, with some of the cells highlighted.
+// Days build these, and the runner draws them to the terminal or an image.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Frame {
+    pub cells: Grid<char>,
+    pub highlights: Grid<Option<Highlight>>,
+}
+
+impl Frame {
+    pub fn new(cells: Grid<char>) -> Frame {
+        let highlights = cells.map(|_| None);
+        Frame { cells, highlights }
+    }
+
+    pub fn highlight(&mut self, pos: Pos, highlight: Highlight) {
+        self.highlights[pos] = Some(highlight);
+    }
+
+    // Frames one above the other, with a blank row between each
+    pub fn stacked(frames: &[Frame]) -> Frame {
+        let rows = frames.iter().map(|f| f.cells.rows() + 1).sum::<usize>();
+        let cols = frames.iter().map(|f| f.cells.cols()).max().unwrap_or(0);
+
+        let mut stacked = Frame::new(Grid::new(rows.saturating_sub(1), cols, ' '));
+        let mut top = 0;
+        for frame in frames {
+            for (pos, char) in frame.cells.iter() {
+                let to = Pos::new(top + pos.row, pos.col);
+                stacked.cells[to] = *char;
+                stacked.highlights[to] = frame.highlights[pos];
+            }
+            top += frame.cells.rows() + 1;
+        }
+
+        stacked
+    }
+
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+
+        for (pos, char) in self.cells.iter() {
+            match self.highlights[pos] {
+                Some(highlight) => write!(out, "{}{}\x1b[0m", highlight.ansi(), char).unwrap(),
+                None => out.push(*char),
+            }
+            if pos.col + 1 == self.cells.cols() {
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+
+    // RGB pixels, row by row, with each cell drawn as a `scale` x `scale` square. Returns the
+    // width and height along with the pixels.
+    pub fn to_rgb(&self, scale: usize) -> (usize, usize, Vec<u8>) {
+        let (width, height) = (self.cells.cols() * scale, self.cells.rows() * scale);
+        let mut pixels = Vec::with_capacity(width * height * 3);
+
+        for y in 0..height {
+            for x in 0..width {
+                let pos = Pos::new(y / scale, x / scale);
+                let color = match (self.highlights[pos], self.cells[pos]) {
+                    (Some(highlight), _) => highlight.rgb(),
+                    (None, '.' | ' ') => BACKGROUND,
+                    (None, _) => FOREGROUND,
+                };
+                pixels.extend(color);
+            }
+        }
+
+        (width, height, pixels)
+    }
+
+    // A binary PPM (P6) image
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height, pixels) = self.to_rgb(scale);
+        let mut ppm = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        ppm.extend(pixels);
+        ppm
+    }
+
+    // Each cell is a square with its char on top, so it stays readable when zoomed in
+    pub fn to_svg(&self) -> String {
+        const CELL: usize = 12;
+        let hex = |[r, g, b]: [u8; 3]| format!("#{:02x}{:02x}{:02x}", r, g, b);
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="{}" text-anchor="middle">"#,
+            self.cells.cols() * CELL,
+            self.cells.rows() * CELL,
+            CELL - 2
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<rect width="100%" height="100%" fill="{}"/>"#,
+            hex(BACKGROUND)
+        )
+        .unwrap();
+
+        for (pos, char) in self.cells.iter() {
+            let (x, y) = (pos.col * CELL, pos.row * CELL);
+
+            if let Some(highlight) = self.highlights[pos] {
+                writeln!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                    x,
+                    y,
+                    CELL,
+                    CELL,
+                    hex(highlight.rgb())
+                )
+                .unwrap();
+            }
+
+            if !matches!(char, '.' | ' ') {
+                let text = match char {
+                    '<' => "&lt;".to_string(),
+                    '>' => "&gt;".to_string(),
+                    '&' => "&amp;".to_string(),
+                    _ => char.to_string(),
+                };
+                writeln!(
+                    svg,
+                    r#"<text x="{}" y="{}" fill="{}">{}</text>"#,
+                    x + CELL / 2,
+                    y + CELL - 3,
+                    hex([230, 230, 230]),
+                    text
+                )
+                .unwrap();
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let mut frame = Frame::new(Grid::from_lines(&["#.", ".#"]).unwrap());
+        frame.highlight(Pos::new(1, 1), Highlight::Red);
+        frame
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        assert_eq!(frame().to_ansi(), "#.\n.\x1b[1;31m#\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        let ppm = frame().to_ppm(2);
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 4 * 4 * 3);
+
+        let pixel = |x: usize, y: usize| {
+            let start = header.len() + (y * 4 + x) * 3;
+            &ppm[start..start + 3]
+        };
+        assert_eq!(pixel(0, 0), FOREGROUND);
+        assert_eq!(pixel(3, 1), BACKGROUND);
+        assert_eq!(pixel(3, 3), Highlight::Red.rgb());
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let svg = frame().to_svg();
+        assert!(svg.starts_with("<svg "));
+        assert_eq!(svg.matches("<text ").count(), 2);
+        assert!(svg.contains(r##"<rect x="12" y="12" width="12" height="12" fill="#dc322f"/>"##));
+    }
+
+    #[test]
+    fn test_stacked() {
+        let stacked = Frame::stacked(&[frame(), Frame::new(Grid::from_lines(&["###"]).unwrap())]);
+        assert_eq!(
+            stacked.cells,
+            Grid::from_lines(&["#. ", ".# ", "   ", "###"]).unwrap()
+        );
+        assert_eq!(stacked.highlights[Pos::new(1, 1)], Some(Highlight::Red));
+    }
+}
@@ -0,0 +1,90 @@
+use std::ops::Range;
+
+// A small, seedable random number generator (SplitMix64). Not for anything security-related, but
+// the same seed always gives the same numbers on every platform, which is what generated inputs
+// need.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // A number in `range`, which must not be empty
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "empty range {:?}", range);
+        let len = (range.end - range.start) as u64;
+        range.start + (self.next_u64() % len) as usize
+    }
+
+    pub fn signed_range(&mut self, range: Range<isize>) -> isize {
+        assert!(!range.is_empty(), "empty range {:?}", range);
+        let len = range.end.abs_diff(range.start) as u64;
+        range.start + (self.next_u64() % len) as isize
+    }
+
+    // True with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut c = Rng::new(43);
+
+        let a: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let b: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        let c: Vec<u64> = (0..5).map(|_| c.next_u64()).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        // The reference SplitMix64 output for seed 0
+        assert_eq!(Rng::new(0).next_u64(), 0xe220a8397b1dcdaf);
+    }
+
+    #[test]
+    fn test_rng_ranges() {
+        let mut rng = Rng::new(7);
+
+        for _ in 0..1000 {
+            assert!((5..10).contains(&rng.range(5..10)));
+            assert!((-3..3).contains(&rng.signed_range(-3..3)));
+        }
+        assert!(!(0..100).any(|_| rng.chance(0.0)));
+        assert!((0..100).all(|_| rng.chance(1.0)));
+
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,253 @@
+use crate::alloc::{self, AllocStats};
+use crate::{normalize, Fixtures, Frame, Input, ParseError, Rng};
+use std::fmt::{Display, Formatter};
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Unknown part: {}", s)),
+        }
+    }
+}
+
+// Implemented by each day. `parse` turns the raw input text into whatever the parts consume; it's
+// called once per part since the parts take ownership of the parsed data. Bad input should come
+// back as a `ParseError` rather than a panic.
+pub trait Solution {
+    const DAY: u8;
+    const FIXTURES: &'static Fixtures;
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    type Parsed;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part_1(parsed: Self::Parsed) -> Self::Answer;
+    fn part_2(parsed: Self::Parsed) -> Self::Answer;
+
+    // Grid-shaped days can draw their state, highlighting whatever led to the answer for `part`
+    fn render(_parsed: Self::Parsed, _part: Part) -> Option<Frame> {
+        None
+    }
+
+    // A random but valid puzzle input, whose `size` (a number of lines, a grid width...) is up to
+    // each day
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+// Object-safe view of a `Solution`, so that days with different parsed/answer types can sit
+// together in the runner's registry.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn fixtures(&self) -> &'static Fixtures;
+    fn parts(&self) -> &'static [Part];
+    fn run_timed(&self, input: &str, part: Part) -> Result<Timed, ParseError>;
+    fn render(&self, input: &str, part: Part) -> Result<Option<Frame>, ParseError>;
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+
+    fn run(&self, input: &str, part: Part) -> Result<String, ParseError> {
+        self.run_timed(input, part).map(|timed| timed.answer)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Timed {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+    // All zero unless `alloc::CountingAlloc` is installed
+    pub parse_alloc: AllocStats,
+    pub solve_alloc: AllocStats,
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn fixtures(&self) -> &'static Fixtures {
+        S::FIXTURES
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn run_timed(&self, input: &str, part: Part) -> Result<Timed, ParseError> {
+        let start = Instant::now();
+        let (parsed, parse_alloc) = alloc::measure(|| S::parse(input));
+        let parsed = parsed?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let (answer, solve_alloc) = alloc::measure(|| match part {
+            Part::One => S::part_1(parsed),
+            Part::Two => S::part_2(parsed),
+        });
+        let solve = start.elapsed();
+
+        Ok(Timed {
+            answer: answer.to_string(),
+            parse,
+            solve,
+            parse_alloc,
+            solve_alloc,
+        })
+    }
+
+    fn render(&self, input: &str, part: Part) -> Result<Option<Frame>, ParseError> {
+        Ok(S::render(S::parse(input)?, part))
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+}
+
+// Entry point for a day's own binary: solves each part of the input selected on the command line,
+// exiting with a message if the arguments are bad or the input can't be read or parsed.
+pub fn run_main<S: Solution + Sync>(solution: S) {
+    let input = Input::from_args().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        eprintln!("Usage: [--input <path>|-]");
+        process::exit(2);
+    });
+
+    let text = S::FIXTURES.text(&input).unwrap_or_else(|err| {
+        eprintln!("Couldn't read input {}: {}", input, err);
+        process::exit(1);
+    });
+    if let Some(warning) = normalize(&text).warning(&input) {
+        eprintln!("warning: {}", warning);
+    }
+
+    println!("day: {:02}", solution.day());
+    for part in solution.parts() {
+        match solution.run(&text, *part) {
+            Ok(answer) => println!("  part {}: {}", part, answer),
+            Err(err) => {
+                eprintln!("{}", err.with_input(&input));
+                process::exit(1);
+            }
+        }
+    }
+}
+
+// Used by the test generated for each day: the test inputs still give the expected answers after
+// an editor has been at them, with a byte order mark, CRLF line endings, trailing spaces and an
+// extra blank line
+pub fn check_mangled_answers<S: Solution>() {
+    for (n, part, expected) in S::FIXTURES.answers {
+        let input = Input::Test(*n);
+        let text = S::FIXTURES.text(&input).unwrap();
+        let mangled = format!(
+            "\u{feff}{}\r\n\r\n",
+            text.trim_end().replace('\n', "  \r\n")
+        );
+
+        let parsed = S::parse(&mangled).unwrap_or_else(|err| panic!("{}", err.with_input(&input)));
+        let answer = match part {
+            Part::One => S::part_1(parsed).to_string(),
+            Part::Two => S::part_2(parsed).to_string(),
+        };
+        assert_eq!(
+            answer,
+            *expected,
+            "day {} part {} of mangled {}",
+            S::DAY,
+            part,
+            input
+        );
+    }
+}
+
+// Used by the tests generated from each day's answers.toml
+pub fn check_answer<S: Solution>(input: Input, part: Part, expected: &str) {
+    let text = S::FIXTURES.text(&input).unwrap();
+    let parsed = S::parse(&text).unwrap_or_else(|err| panic!("{}", err.with_input(&input)));
+    let answer = match part {
+        Part::One => S::part_1(parsed).to_string(),
+        Part::Two => S::part_2(parsed).to_string(),
+    };
+
+    assert_eq!(
+        answer,
+        expected,
+        "day {} part {} of {}",
+        S::DAY,
+        part,
+        input
+    );
+}
+
+// Used by each day's generator tests: every seed's input should parse and solve without panicking,
+// both at `size` and at size 0, which `aoc gen --size 0` allows
+pub fn check_generator<S: Solution>(seeds: std::ops::Range<u64>, size: usize) {
+    for size in [0, size] {
+        for seed in seeds.clone() {
+            let input = S::generate(&mut Rng::new(seed), size).expect("no generator");
+            let parsed = |part| {
+                S::parse(&input).unwrap_or_else(|err| {
+                    panic!(
+                        "day {} seed {} size {} part {}: {}\n{}",
+                        S::DAY,
+                        seed,
+                        size,
+                        part,
+                        err,
+                        input
+                    )
+                })
+            };
+
+            for part in S::PARTS {
+                match part {
+                    Part::One => S::part_1(parsed(part)).to_string(),
+                    Part::Two => S::part_2(parsed(part)).to_string(),
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+// How much of a solution's working `trace!` reports. Each level includes everything below it, and
+// `Summary` stays short even on the real inputs.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug)]
+pub enum Level {
+    // A handful of lines per part: the overall approach and totals
+    Summary = 1,
+    // A line per input item (line, card, game, pattern...)
+    Steps = 2,
+    // Everything, including the inner loops
+    Detail = 3,
+}
+
+impl Level {
+    pub fn from_number(level: u8) -> Option<Level> {
+        match level {
+            1 => Some(Level::Summary),
+            2 => Some(Level::Steps),
+            3 => Some(Level::Detail),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Event {
+    pub level: Level,
+    pub message: String,
+}
+
+// Beyond this many events a trace just counts what it drops, so a detailed trace of a real input
+// doesn't eat all the memory.
+pub const MAX_EVENTS: usize = 10_000;
+
+// 0 when nobody is listening, which keeps `trace!` down to a single atomic load
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+static EVENTS: Mutex<Trace> = Mutex::new(Trace {
+    events: vec![],
+    dropped: 0,
+});
+// Only one `explain` can listen at a time
+static EXPLAINING: Mutex<()> = Mutex::new(());
+
+#[derive(Eq, PartialEq, Default, Clone, Debug)]
+pub struct Trace {
+    pub events: Vec<Event>,
+    // Events past `MAX_EVENTS` that weren't kept
+    pub dropped: usize,
+}
+
+impl Trace {
+    // Indented by level, one event per line
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        for event in self.events.iter() {
+            let indent = "  ".repeat(event.level as usize - 1);
+            out.push_str(&format!("{}{}\n", indent, event.message));
+        }
+        if self.dropped > 0 {
+            out.push_str(&format!("... and {} more events\n", self.dropped));
+        }
+
+        out
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+// Used by `trace!`, which checks `enabled` first so the message is only formatted when needed
+pub fn record(level: Level, message: String) {
+    let mut trace = EVENTS.lock().unwrap_or_else(|err| err.into_inner());
+    if trace.events.len() < MAX_EVENTS {
+        trace.events.push(Event { level, message });
+    } else {
+        trace.dropped += 1;
+    }
+}
+
+// Runs `f`, collecting everything it traces at or below `level`
+pub fn explain<R>(level: Level, f: impl FnOnce() -> R) -> (R, Trace) {
+    let _explaining = EXPLAINING.lock().unwrap_or_else(|err| err.into_inner());
+
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    let result = f();
+    LEVEL.store(0, Ordering::Relaxed);
+
+    let mut trace = EVENTS.lock().unwrap_or_else(|err| err.into_inner());
+    (result, std::mem::take(&mut *trace))
+}
+
+// Records a formatted message at the given level, when something is explaining:
+//
+//   trace!(Steps, "card {} has {} matches", card.id, card.match_count);
+#[macro_export]
+macro_rules! trace {
+    ($level:ident, $($arg:tt)+) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::record($crate::trace::Level::$level, format!($($arg)+));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn traced() -> usize {
+        trace!(Summary, "summing {} numbers", 3);
+        (1..=3).inspect(|n| trace!(Steps, "adding {}", n)).sum()
+    }
+
+    #[test]
+    fn test_explain() {
+        let (sum, trace) = explain(Level::Steps, traced);
+        assert_eq!(sum, 6);
+        assert_eq!(
+            trace.render(),
+            "summing 3 numbers\n  adding 1\n  adding 2\n  adding 3\n"
+        );
+
+        let (_, trace) = explain(Level::Summary, traced);
+        assert_eq!(trace.events.len(), 1);
+    }
+
+    #[test]
+    fn test_explain_drops_events_past_max() {
+        let (_, trace) = explain(Level::Detail, || {
+            for n in 0..MAX_EVENTS + 5 {
+                trace!(Detail, "{}", n);
+            }
+        });
+        assert_eq!(trace.events.len(), MAX_EVENTS);
+        assert_eq!(trace.dropped, 5);
+        assert!(trace.render().ends_with("... and 5 more events\n"));
+    }
+}
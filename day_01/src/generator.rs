@@ -0,0 +1,40 @@
+use aoc_core::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// `size` lines (at least one) of letters, digits and spelled-out digits. Every line has at least
+// one real digit, since part 1 needs one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..size.max(1) {
+        let mut pieces = vec![rng.range(1..10).to_string()];
+        for _ in 0..rng.range(0..8) {
+            let piece = match rng.range(0..3) {
+                0 => rng.range(1..10).to_string(),
+                1 => rng.choose(&WORDS).to_string(),
+                _ => (0..rng.range(1..4))
+                    .map(|_| (b'a' + rng.range(0..26) as u8) as char)
+                    .collect(),
+            };
+            pieces.push(piece);
+        }
+
+        rng.shuffle(&mut pieces);
+        lines.push(pieces.concat());
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day01;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day01>(0..20, 50);
+    }
+}
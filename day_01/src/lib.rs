@@ -0,0 +1,114 @@
+//! Day 1: Trebuchet?!
+#![warn(missing_docs)]
+
+mod generator;
+
+use aoc_core::{parse_lines, trace, Fixtures, ParseError, Rng, Solution};
+use regex::Regex;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_1(lines: Vec<String>) -> usize {
+        part_1(lines)
+    }
+
+    fn part_2(lines: Vec<String>) -> usize {
+        part_2(lines)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// Each line originally contained a calibration value that the Elves need to recover. On each line,
+/// the calibration value can be found by combining the first digit and the last digit to form a
+/// single two-digit number. What is the sum of all of the calibration values?
+pub fn part_1(lines: Vec<String>) -> usize {
+    let mut sum = 0;
+
+    fn find_first_digit(mut chars: impl Iterator<Item = char>) -> usize {
+        let digit = chars.find(|c| c.is_ascii_digit()).unwrap();
+        digit.to_digit(10).unwrap() as usize
+    }
+
+    trace!(
+        Summary,
+        "Combining the first and last digit of {} lines",
+        lines.len()
+    );
+    for line in lines {
+        let first_digit = find_first_digit(line.chars());
+        let last_digit = find_first_digit(line.chars().rev());
+        trace!(Steps, "{}: {}{}", line, first_digit, last_digit);
+        sum += first_digit * 10 + last_digit;
+    }
+
+    trace!(Summary, "The calibration values add up to {}", sum);
+    sum
+}
+
+/// It looks like some of the digits are actually spelled out with letters. What is the sum?
+pub fn part_2(lines: Vec<String>) -> usize {
+    let mut sum = 0;
+
+    let nums = "([1-9]|one|two|three|four|five|six|seven|eight|nine)";
+    let first_re = Regex::new(&format!(r"^.*?{}", nums)).unwrap();
+    let last_re = Regex::new(&format!(r"^.*{}", nums)).unwrap();
+
+    fn to_digit(s: &str) -> usize {
+        match s {
+            "1" | "one" => 1,
+            "2" | "two" => 2,
+            "3" | "three" => 3,
+            "4" | "four" => 4,
+            "5" | "five" => 5,
+            "6" | "six" => 6,
+            "7" | "seven" => 7,
+            "8" | "eight" => 8,
+            "9" | "nine" => 9,
+            _ => panic!("Unknown digit: {}", s),
+        }
+    }
+
+    trace!(
+        Summary,
+        "Combining the first and last digit or digit word of {} lines",
+        lines.len()
+    );
+    for line in lines {
+        let first_caps = first_re.captures(&line).unwrap();
+        let first_digit = to_digit(&first_caps[1]);
+
+        let last_caps = last_re.captures(&line).unwrap();
+        let last_digit = to_digit(&last_caps[1]);
+
+        trace!(
+            Steps,
+            "{}: {} ({}) and {} ({})",
+            line,
+            &first_caps[1],
+            first_digit,
+            &last_caps[1],
+            last_digit
+        );
+        sum += first_digit * 10 + last_digit;
+    }
+
+    trace!(Summary, "The calibration values add up to {}", sum);
+    sum
+}
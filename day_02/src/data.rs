@@ -1,41 +1,56 @@
+use aoc_core::ParseError;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::str::FromStr;
 
+/// A `Game N: ...` line
 #[derive(Eq, PartialEq, Debug)]
 pub struct Game {
+    /// The game's number
     pub id: usize,
+    /// Each handful of cubes shown, in order
     pub rounds: Vec<Round>,
 }
 
+/// One handful of cubes; colors that weren't shown are 0
 #[derive(Default, Eq, PartialEq, Debug)]
 pub struct Round {
+    /// Red cubes
     pub red: usize,
+    /// Green cubes
     pub green: usize,
+    /// Blue cubes
     pub blue: usize,
 }
 
 impl FromStr for Game {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        static ID_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^Game (\d+):").unwrap());
-        static ROUND_REGEX: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"(\d+) (red|green|blue)").unwrap());
+        static ID_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^Game (\d+): ").unwrap());
 
-        let id = ID_REGEX.captures(s).unwrap()[1].parse().unwrap();
+        let id_caps = ID_REGEX
+            .captures(s)
+            .ok_or_else(|| ParseError::new(1, "`Game <id>:`"))?;
+        let id = id_caps[1]
+            .parse()
+            .map_err(|_| ParseError::at(s, &id_caps[1], "a game ID"))?;
         let mut rounds = vec![];
 
-        for r in s.split(';') {
+        // Rounds are separated by `; `, and each is a `<count> <color>` per color, separated by `, `
+        for r in s[id_caps[0].len()..].split("; ") {
             let mut round = Round::default();
 
-            for cap in ROUND_REGEX.captures_iter(r) {
-                let count = cap[1].parse().unwrap();
-                match &cap[2] {
+            for token in r.split(", ") {
+                let (count, color) = token
+                    .split_once(' ')
+                    .and_then(|(count, color)| Some((count.parse().ok()?, color)))
+                    .ok_or_else(|| ParseError::at(s, token, "a cube count and color"))?;
+                match color {
                     "red" => round.red = count,
                     "green" => round.green = count,
                     "blue" => round.blue = count,
-                    _ => panic!(),
+                    _ => return Err(ParseError::at(s, token, "a cube count and color")),
                 }
             }
 
@@ -49,12 +64,13 @@ impl FromStr for Game {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{get_input, Input};
+    use crate::FIXTURES;
+    use aoc_core::Input;
 
     #[test]
     fn test_thing_from_str() {
         assert_eq!(
-            get_input::<Game>(Input::Test1)[0],
+            FIXTURES.get_input::<Game>(Input::Test(1)).unwrap()[0],
             Game {
                 id: 1,
                 rounds: vec![
@@ -77,4 +93,28 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_game_from_str_error() {
+        assert_eq!(
+            "Gme 1: 3 blue".parse::<Game>(),
+            Err(ParseError::new(1, "`Game <id>:`"))
+        );
+        assert_eq!(
+            "Game 99999999999999999999: 3 blue".parse::<Game>(),
+            Err(ParseError::new(6, "a game ID"))
+        );
+        assert_eq!(
+            "Game 1: banana".parse::<Game>(),
+            Err(ParseError::new(9, "a cube count and color"))
+        );
+        assert_eq!(
+            "Game 1: 3 purple, 4 red".parse::<Game>(),
+            Err(ParseError::new(9, "a cube count and color"))
+        );
+        assert_eq!(
+            "Game 1: 3 blue; 4 red,5 green".parse::<Game>(),
+            Err(ParseError::new(17, "a cube count and color"))
+        );
+    }
 }
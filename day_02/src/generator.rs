@@ -0,0 +1,36 @@
+use aoc_core::Rng;
+
+// `size` games (at least one) of 1 to 6 rounds, each showing up to 20 cubes of some of the colors
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for id in 1..=size.max(1) {
+        let rounds: Vec<String> = (0..rng.range(1..7))
+            .map(|_| {
+                let mut colors = vec!["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                colors.truncate(rng.range(1..4));
+
+                colors
+                    .iter()
+                    .map(|color| format!("{} {}", rng.range(1..21), color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+
+        lines.push(format!("Game {}: {}", id, rounds.join("; ")));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day02;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day02>(0..20, 50);
+    }
+}
@@ -0,0 +1,90 @@
+//! Day 2: Cube Conundrum
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{parse_lines, trace, Fixtures, ParseError, Rng, Solution};
+pub use data::*;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Vec<Game>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_1(games: Vec<Game>) -> usize {
+        part_1(games)
+    }
+
+    fn part_2(games: Vec<Game>) -> usize {
+        part_2(games)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// Determine which games would have been possible if the bag had been loaded with only 12 red cubes,
+/// 13 green cubes, and 14 blue cubes. What is the sum of the IDs of those games?
+pub fn part_1(games: Vec<Game>) -> usize {
+    let mut sum = 0;
+
+    for game in games {
+        let possible = game
+            .rounds
+            .iter()
+            .all(|game| game.red <= 12 && game.green <= 13 && game.blue <= 14);
+
+        trace!(
+            Steps,
+            "Game {} is {}",
+            game.id,
+            if possible { "possible" } else { "impossible" }
+        );
+        if possible {
+            sum += game.id;
+        }
+    }
+
+    trace!(Summary, "The possible games' IDs add up to {}", sum);
+    sum
+}
+
+/// In each game you played, what is the fewest number of cubes of each color that could have been in
+/// the bag to make the game possible? The power of a set of cubes is equal to the numbers of red,
+/// green, and blue cubes multiplied together. Find the power of each game. What is the sum?
+pub fn part_2(games: Vec<Game>) -> usize {
+    let mut sum = 0;
+
+    for game in games {
+        let red_max = game.rounds.iter().map(|g| g.red).max().unwrap();
+        let green_max = game.rounds.iter().map(|g| g.green).max().unwrap();
+        let blue_max = game.rounds.iter().map(|g| g.blue).max().unwrap();
+        let power = red_max * green_max * blue_max;
+        trace!(
+            Steps,
+            "Game {} needs at least {} red, {} green and {} blue: power {}",
+            game.id,
+            red_max,
+            green_max,
+            blue_max,
+            power
+        );
+        sum += power;
+    }
+
+    trace!(Summary, "The powers add up to {}", sum);
+    sum
+}
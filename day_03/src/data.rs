@@ -1,121 +1,106 @@
-use std::collections::BTreeMap;
+use aoc_core::{Grid, Pos};
+use std::collections::BTreeSet;
 
+/// A number in the schematic, which starts at `pos` and runs `len` digits to the right
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
-pub struct Coord {
-    pub row: isize,
-    pub col: isize,
-    pub len: isize,
+pub struct Number {
+    /// Where its first digit is
+    pub pos: Pos,
+    /// How many digits it has
+    pub len: usize,
+    /// The number itself
+    pub value: usize,
 }
 
-type NumberMap = BTreeMap<Coord, usize>;
-type PartMap = BTreeMap<Coord, char>;
+impl Number {
+    /// Every cell touching the number, even diagonally
+    pub fn adjacent(&self, schematic: &Grid<char>) -> BTreeSet<Pos> {
+        let digits: BTreeSet<Pos> = (0..self.len)
+            .map(|offset| Pos::new(self.pos.row, self.pos.col + offset))
+            .collect();
 
-pub fn build_number_map(lines: &[String]) -> NumberMap {
-    let mut numbers = BTreeMap::new();
+        digits
+            .iter()
+            .flat_map(|digit| schematic.neighbours_8(*digit))
+            .filter(|pos| !digits.contains(pos))
+            .collect()
+    }
+}
 
-    for (row, line) in lines.iter().enumerate() {
-        let mut col_idx = None;
-        let mut num = String::new();
+/// Anything but a digit or `.`
+pub fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
 
-        let mut chars = line.chars().enumerate().peekable();
-        while let Some((col, c)) = chars.next() {
-            if c.is_ascii_digit() {
-                col_idx.get_or_insert(col);
-                num.push(c);
-            }
+/// Every number in the schematic, row by row
+pub fn find_numbers(schematic: &Grid<char>) -> Vec<Number> {
+    let mut numbers = vec![];
 
-            let number_is_built = col_idx.is_some();
-            let end_of_number = chars.peek().is_some_and(|(_i, c)| !c.is_ascii_digit());
-            let end_of_line = chars.peek().is_none();
+    for (row, cells) in schematic.iter_rows().enumerate() {
+        let mut col = 0;
 
-            if number_is_built && (end_of_number || end_of_line) {
-                numbers.insert(
-                    Coord {
-                        row: row as isize,
-                        col: col_idx.unwrap() as isize,
-                        len: num.len() as isize,
-                    },
-                    num.parse().unwrap(),
-                );
+        while col < cells.len() {
+            let len = cells[col..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
 
-                col_idx = None;
-                num.clear();
+            if len > 0 {
+                numbers.push(Number {
+                    pos: Pos::new(row, col),
+                    len,
+                    value: cells[col..col + len]
+                        .iter()
+                        .collect::<String>()
+                        .parse()
+                        .unwrap(),
+                });
             }
-        }
-    }
 
-    numbers
-}
-
-pub fn build_part_map(lines: &[String]) -> PartMap {
-    let mut parts = BTreeMap::new();
-
-    for (row, line) in lines.iter().enumerate() {
-        for (col, c) in line.chars().enumerate() {
-            if c.is_ascii_digit() || c == '.' {
-                continue;
-            } else {
-                parts.insert(
-                    Coord {
-                        row: row as isize,
-                        col: col as isize,
-                        len: 1,
-                    },
-                    c,
-                );
-            }
+            col += len.max(1);
         }
     }
 
-    parts
+    numbers
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{get_input, Input};
+    use crate::{Day03, FIXTURES};
+    use aoc_core::{Input, Solution};
+
+    fn schematic() -> Grid<char> {
+        Day03::parse(&FIXTURES.text(&Input::Test(1)).unwrap()).unwrap()
+    }
 
     #[test]
-    fn test_build_number_map() {
-        let lines = get_input::<String>(Input::Test1);
-        let number_map = build_number_map(&lines);
+    fn test_find_numbers() {
+        let numbers = find_numbers(&schematic());
         assert_eq!(
-            number_map[&Coord {
-                row: 0,
-                col: 0,
-                len: 3
-            }],
-            467
-        );
-        assert_eq!(
-            number_map[&Coord {
-                row: 5,
-                col: 7,
-                len: 2
-            }],
-            58
+            numbers[0],
+            Number {
+                pos: Pos::new(0, 0),
+                len: 3,
+                value: 467
+            }
         );
+        assert!(numbers.contains(&Number {
+            pos: Pos::new(5, 7),
+            len: 2,
+            value: 58
+        }));
     }
 
     #[test]
-    fn test_build_part_map() {
-        let lines = get_input::<String>(Input::Test1);
-        let part_map = build_part_map(&lines);
-        assert_eq!(
-            part_map[&Coord {
-                row: 1,
-                col: 3,
-                len: 1
-            }],
-            '*'
-        );
-        assert_eq!(
-            part_map[&Coord {
-                row: 5,
-                col: 5,
-                len: 1
-            }],
-            '+'
-        );
+    fn test_number_adjacent() {
+        let schematic = schematic();
+        let numbers = find_numbers(&schematic);
+
+        let adjacent = numbers[0].adjacent(&schematic);
+        assert_eq!(adjacent.len(), 5);
+        assert!(adjacent.contains(&Pos::new(1, 3)));
+        assert_eq!(schematic[Pos::new(1, 3)], '*');
+        assert!(is_symbol(schematic[Pos::new(5, 5)]));
     }
 }
@@ -0,0 +1,44 @@
+use aoc_core::Rng;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+// A `size` x `size` schematic of numbers (up to 3 digits) and symbols, mostly `.`
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..size {
+        let mut line = String::new();
+
+        while line.len() < size {
+            let space = size - line.len();
+
+            if rng.chance(0.2) {
+                let len = rng.range(1..4).min(space);
+                let number = rng.range(10usize.pow(len as u32 - 1)..10usize.pow(len as u32));
+                line.push_str(&number.to_string());
+                // Keep numbers apart, so they don't run together into one
+                if line.len() < size {
+                    line.push('.');
+                }
+            } else if rng.chance(0.1) {
+                line.push(*rng.choose(&SYMBOLS));
+            } else {
+                line.push('.');
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day03;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day03>(0..20, 30);
+    }
+}
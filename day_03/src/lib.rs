@@ -0,0 +1,171 @@
+//! Day 3: Gear Ratios
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{
+    parse_whole, trace, Fixtures, Frame, Grid, Highlight, ParseError, Part, Pos, Rng, Solution,
+};
+pub use data::*;
+use std::collections::BTreeMap;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Grid<char>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse_whole(input, |lines| Grid::from_lines(&lines))
+    }
+
+    fn part_1(schematic: Grid<char>) -> usize {
+        part_1(schematic)
+    }
+
+    fn part_2(schematic: Grid<char>) -> usize {
+        part_2(schematic)
+    }
+
+    fn render(schematic: Grid<char>, part: Part) -> Option<Frame> {
+        Some(render(schematic, part))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// Any number adjacent to a symbol, even diagonally, is a "part number". What is the sum of all part
+/// numbers in the engine schematic? 528231
+pub fn part_1(schematic: Grid<char>) -> usize {
+    let part_numbers = part_numbers(&schematic);
+    let sum = part_numbers.iter().map(|number| number.value).sum();
+
+    trace!(
+        Summary,
+        "{} part numbers add up to {}",
+        part_numbers.len(),
+        sum
+    );
+    sum
+}
+
+/// A gear is any * symbol that is adjacent to exactly two part numbers. Its gear ratio is the result
+/// of multiplying those two numbers together. What is the sum of all gear ratios?
+pub fn part_2(schematic: Grid<char>) -> usize {
+    let mut sum = 0;
+
+    for (pos, nums) in gear_map(&schematic).iter() {
+        if nums.len() == 2 {
+            trace!(
+                Steps,
+                "Gear at row {}, column {}: {} * {}",
+                pos.row + 1,
+                pos.col + 1,
+                nums[0].value,
+                nums[1].value
+            );
+            sum += nums[0].value * nums[1].value;
+        } else {
+            trace!(
+                Detail,
+                "* at row {}, column {} touches {} number(s), so isn't a gear",
+                pos.row + 1,
+                pos.col + 1,
+                nums.len()
+            );
+        }
+    }
+
+    trace!(Summary, "The gear ratios add up to {}", sum);
+    sum
+}
+
+fn part_numbers(schematic: &Grid<char>) -> Vec<Number> {
+    let numbers = find_numbers(schematic);
+    trace!(Summary, "Found {} numbers in the schematic", numbers.len());
+
+    numbers
+        .into_iter()
+        .filter(|number| {
+            let symbol = number
+                .adjacent(schematic)
+                .into_iter()
+                .find(|pos| is_symbol(schematic[*pos]));
+
+            match symbol {
+                Some(pos) => trace!(
+                    Steps,
+                    "{} touches {} at row {}, column {}",
+                    number.value,
+                    schematic[pos],
+                    pos.row + 1,
+                    pos.col + 1
+                ),
+                None => trace!(Detail, "{} doesn't touch a symbol", number.value),
+            }
+            symbol.is_some()
+        })
+        .collect()
+}
+
+// Each * symbol, and the numbers adjacent to it
+fn gear_map(schematic: &Grid<char>) -> BTreeMap<Pos, Vec<Number>> {
+    let mut gear_map = BTreeMap::new();
+
+    for number in find_numbers(schematic) {
+        if let Some(gear) = number
+            .adjacent(schematic)
+            .into_iter()
+            .find(|pos| schematic[*pos] == '*')
+        {
+            gear_map.entry(gear).or_insert_with(Vec::new).push(number);
+        }
+    }
+
+    gear_map
+}
+
+// Part numbers and the symbols they touch, or for part 2 the gears and their two numbers
+fn render(schematic: Grid<char>, part: Part) -> Frame {
+    let mut frame = Frame::new(schematic.clone());
+    let mut highlight_number = |number: &Number| {
+        for offset in 0..number.len {
+            frame.highlight(
+                Pos::new(number.pos.row, number.pos.col + offset),
+                Highlight::Green,
+            );
+        }
+    };
+
+    match part {
+        Part::One => {
+            part_numbers(&schematic).iter().for_each(highlight_number);
+            for (pos, char) in schematic.iter() {
+                if is_symbol(*char) {
+                    frame.highlight(pos, Highlight::Yellow);
+                }
+            }
+        }
+        Part::Two => {
+            let gears = gear_map(&schematic);
+            let gears: Vec<_> = gears.iter().filter(|(_, nums)| nums.len() == 2).collect();
+            for (_gear, nums) in gears.iter() {
+                nums.iter().for_each(&mut highlight_number);
+            }
+            for (gear, _nums) in gears {
+                frame.highlight(*gear, Highlight::Red);
+            }
+        }
+    }
+
+    frame
+}
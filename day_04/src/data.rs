@@ -1,45 +1,57 @@
+use aoc_core::ParseError;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashSet;
 use std::str::FromStr;
 
+/// A `Card N: winning | mine` line
 #[derive(Eq, PartialEq, Debug)]
 pub struct Card {
+    /// The card's number
     pub id: usize,
+    /// The numbers left of the `|`
     pub winning_nums: HashSet<usize>,
+    /// The numbers right of the `|`
     pub my_nums: HashSet<usize>,
+    /// How many of my numbers are winning numbers
     pub match_count: usize,
 }
 
+/// A set of card numbers
 pub type Nums = HashSet<usize>;
 
 impl Card {
+    /// 1 point for the first match, doubling for each one after that
     pub fn score(&self) -> usize {
         match self.match_count {
             0 => 0,
             1 => 1,
-            _ => 2 << (self.match_count - 2),
+            _ => 2usize.pow(self.match_count as u32 - 1),
         }
     }
 }
 
 impl FromStr for Card {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         static RE: Lazy<Regex> =
             Lazy::new(|| Regex::new(r"^Card\s+(\d+): ([\d\s]+) \| ([\d\s]+)$").unwrap());
 
-        fn get_nums(s: &str) -> Nums {
-            s.split_ascii_whitespace()
-                .map(|n| n.parse().unwrap())
+        let get_nums = |nums: &str| -> Result<Nums, ParseError> {
+            nums.split_ascii_whitespace()
+                .map(|n| n.parse().map_err(|_| ParseError::at(s, n, "a number")))
                 .collect()
-        }
+        };
 
-        let caps = RE.captures(s).unwrap();
-        let id = caps[1].parse().unwrap();
-        let winning_nums = get_nums(&caps[2]);
-        let my_nums = get_nums(&caps[3]);
+        let caps = RE
+            .captures(s)
+            .ok_or_else(|| ParseError::new(1, "`Card <id>: <numbers> | <numbers>`"))?;
+        let id = caps[1]
+            .parse()
+            .map_err(|_| ParseError::at(s, &caps[1], "a card ID"))?;
+        let winning_nums = get_nums(&caps[2])?;
+        let my_nums = get_nums(&caps[3])?;
         let match_count = winning_nums.intersection(&my_nums).count();
 
         Ok(Card {
@@ -54,12 +66,13 @@ impl FromStr for Card {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{get_input, Input};
+    use crate::FIXTURES;
+    use aoc_core::Input;
 
     #[test]
     fn test_card_from_str() {
         assert_eq!(
-            get_input::<Card>(Input::Test1)[0],
+            FIXTURES.get_input::<Card>(Input::Test(1)).unwrap()[0],
             Card {
                 id: 1,
                 winning_nums: [41, 48, 83, 86, 17].into(),
@@ -69,10 +82,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_card_from_str_error() {
+        assert_eq!(
+            "Card 1: 41 48 83".parse::<Card>(),
+            Err(ParseError::new(1, "`Card <id>: <numbers> | <numbers>`"))
+        );
+        assert_eq!(
+            "Card 1: 41 99999999999999999999 | 83".parse::<Card>(),
+            Err(ParseError::new(12, "a number"))
+        );
+    }
+
     #[test]
     fn test_card_score() {
         assert_eq!(
-            get_input::<Card>(Input::Test1)
+            FIXTURES
+                .get_input::<Card>(Input::Test(1))
+                .unwrap()
                 .iter()
                 .map(Card::score)
                 .collect::<Vec<_>>(),
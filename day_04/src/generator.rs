@@ -0,0 +1,43 @@
+use aoc_core::Rng;
+
+// `size` cards (at least one) with 5 winning numbers and 8 of your own. A card never wins copies of
+// cards past the end of the table.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut lines = vec![];
+
+    for id in 1..=size {
+        let mut numbers: Vec<usize> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+
+        let match_count = rng.range(0..6).min(size - id);
+        let winning = &numbers[..5];
+        let mut mine = [&winning[..match_count], &numbers[5..13 - match_count]].concat();
+        rng.shuffle(&mut mine);
+
+        let format = |nums: &[usize]| {
+            nums.iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        lines.push(format!(
+            "Card {:>3}: {} | {}",
+            id,
+            format(winning),
+            format(&mine)
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day04;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day04>(0..20, 50);
+    }
+}
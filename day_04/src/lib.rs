@@ -0,0 +1,127 @@
+//! Day 4: Scratchcards
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{parse_lines, trace, Fixtures, ParseError, Rng, Solution};
+pub use data::*;
+use std::collections::BTreeMap;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Vec<Card>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_1(cards: Vec<Card>) -> usize {
+        part_1(cards)
+    }
+
+    fn part_2(cards: Vec<Card>) -> usize {
+        part_2(cards)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// Each card has a list of winning numbers, a pipe character, and then a list of numbers you have.
+/// The first match makes the card worth one point, and each subsequent match doubles the value.
+pub fn part_1(cards: Vec<Card>) -> usize {
+    let mut sum = 0;
+
+    for card in cards.iter() {
+        trace!(
+            Steps,
+            "Card {}: {} matches, worth {}",
+            card.id,
+            card.match_count,
+            card.score()
+        );
+        sum += card.score();
+    }
+
+    trace!(Summary, "{} cards are worth {} points", cards.len(), sum);
+    sum
+}
+
+/// For M matches on a card, you win one extra copy of each of the next M cards. How many total
+/// scratchcards do you end up with?
+pub fn part_2(cards: Vec<Card>) -> usize {
+    // This function recursively counts the number of cards "under" a given card ID. Given a Card #1
+    // that has 4 matches, it'll return 4 + the recursive number of matches in Cards #2 thru #5. It
+    // uses memoization for performance, hence needing to pass/return/munge "memo_under_counts".
+    fn count_under(
+        id: usize,
+        match_counts_by_id: &BTreeMap<usize, usize>,
+        mut memo_under_counts: BTreeMap<usize, usize>,
+    ) -> (usize, BTreeMap<usize, usize>) {
+        if let Some(memo_under_count) = memo_under_counts.get(&id) {
+            trace!(
+                Detail,
+                "Card {} wins {} cards (memoized)",
+                id,
+                memo_under_count
+            );
+            return (*memo_under_count, memo_under_counts);
+        }
+
+        // This card's "under count" is the number of matches it has, PLUS the recursive count
+        let match_count = match_counts_by_id[&id];
+        let mut under_count = match_count;
+
+        for under_id in (id + 1)..=(id + match_count) {
+            // Recurse, passing (and then restoring) the memoization map
+            let res = count_under(under_id, match_counts_by_id, memo_under_counts);
+            let count = res.0;
+            memo_under_counts = res.1;
+
+            under_count += count;
+            memo_under_counts.entry(under_id).or_insert(count);
+        }
+
+        (under_count, memo_under_counts)
+    }
+
+    let match_counts_by_id = cards
+        .iter()
+        .map(|c| (c.id, c.match_count))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut total_cards = 0;
+    let mut under_counts = BTreeMap::new();
+
+    for card in cards.iter() {
+        let res = count_under(card.id, &match_counts_by_id, under_counts);
+        let count = res.0;
+        under_counts = res.1;
+        trace!(
+            Steps,
+            "Card {} wins {} more cards, all told",
+            card.id,
+            count
+        );
+        total_cards += 1 + count;
+    }
+
+    trace!(
+        Summary,
+        "{} original cards win {} copies, for {} in total",
+        cards.len(),
+        total_cards - cards.len(),
+        total_cards
+    );
+    total_cards
+}
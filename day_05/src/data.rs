@@ -1,4 +1,5 @@
-use std::ops::RangeInclusive;
+use aoc_core::ParseError;
+use std::ops::{Range, RangeInclusive};
 
 #[derive(Eq, PartialEq, Debug)]
 enum ParseState {
@@ -10,47 +11,46 @@ enum ParseState {
     Done,
 }
 
-pub fn parse_input(lines: &[String]) -> (Seeds, Vec<Map>) {
+/// Parses the `seeds:` line, then each `<name> map:` and its ranges
+pub fn parse_input(lines: &[String]) -> Result<Almanac, ParseError> {
     use ParseState::*;
 
     let mut seeds = None;
     let mut maps = vec![];
     let mut state = SeedsLine;
-    let mut lines = lines.iter().peekable();
+    let line_count = lines.len();
+    let mut lines = lines.iter().map(String::as_str).enumerate().peekable();
+
+    // For when the input runs out partway through
+    let end_of_input = |expected: &str| ParseError::new(1, expected).with_line(line_count + 1, "");
 
     loop {
         match state {
             SeedsLine => {
-                let line = lines.next().unwrap();
-                seeds = Some(line.split(' ').filter_map(|s| s.parse().ok()).collect());
+                let (idx, line) = lines.next().ok_or_else(|| end_of_input("`seeds:`"))?;
+                seeds = Some(parse_seeds(line).map_err(|err| err.with_line(idx + 1, line))?);
                 state = Waiting;
             }
             Waiting => match lines.peek() {
-                Some(l) if l.is_empty() => {
+                Some((_, "")) => {
                     lines.next();
                 }
                 Some(_) => state = InitMap,
                 None => state = Done,
             },
             InitMap => {
-                let line = lines.next().unwrap();
-                let map = Map::new(line.split(' ').next().unwrap().to_string());
-                state = AddRanges(map);
+                let (idx, line) = lines.next().ok_or_else(|| end_of_input("`<name> map:`"))?;
+                let name = line
+                    .strip_suffix(" map:")
+                    .ok_or_else(|| ParseError::new(1, "`<name> map:`").with_line(idx + 1, line))?;
+                state = AddRanges(Map::new(name.to_string()));
             }
             AddRanges(mut map) => {
-                let line = lines.next().unwrap();
-                let nums: Vec<usize> = line.split(' ').map(|n| n.parse().unwrap()).collect();
-                let dest_range_start = nums[0];
-                let src_range_start = nums[1];
-                let range_length = nums[2];
-                let range = RangeMap {
-                    source_range: src_range_start..=(src_range_start + range_length - 1),
-                    destination_range: dest_range_start..=(dest_range_start + range_length - 1),
-                };
+                let (idx, line) = lines.next().ok_or_else(|| end_of_input("a range"))?;
+                let range = parse_range_map(line).map_err(|err| err.with_line(idx + 1, line))?;
                 map.ranges.push(range);
 
-                let next_is_empty =
-                    lines.peek().is_none() || lines.peek().is_some_and(|l| l.is_empty());
+                let next_is_empty = lines.peek().is_none_or(|(_, l)| l.is_empty());
                 if next_is_empty {
                     state = FinishMap(map);
                 } else {
@@ -65,18 +65,64 @@ pub fn parse_input(lines: &[String]) -> (Seeds, Vec<Map>) {
         }
     }
 
-    (seeds.unwrap(), maps)
+    Ok((seeds.unwrap(), maps))
+}
+
+fn parse_seeds(line: &str) -> Result<Seeds, ParseError> {
+    let seeds = line
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::new(1, "`seeds:`"))?;
+
+    seeds
+        .split_whitespace()
+        .map(|n| {
+            n.parse()
+                .map_err(|_| ParseError::at(line, n, "a seed number"))
+        })
+        .collect()
+}
+
+// Parses a `<destination start> <source start> <length>` line
+fn parse_range_map(line: &str) -> Result<RangeMap, ParseError> {
+    let nums = line
+        .split(' ')
+        .map(|n| n.parse().map_err(|_| ParseError::at(line, n, "a number")))
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    let [dest_range_start, src_range_start, range_length] = nums[..] else {
+        return Err(ParseError::new(1, "3 numbers"));
+    };
+
+    let range_end = |start: usize| {
+        start
+            .checked_add(range_length)
+            .and_then(|end| end.checked_sub(1))
+            .filter(|end| *end >= start)
+            .ok_or_else(|| ParseError::new(1, "a non-empty range that fits in a usize"))
+    };
+
+    Ok(RangeMap {
+        source_range: src_range_start..=range_end(src_range_start)?,
+        destination_range: dest_range_start..=range_end(dest_range_start)?,
+    })
 }
 
+/// The numbers on the `seeds:` line
 pub type Seeds = Vec<usize>;
+/// The seeds, and the maps in the order they're applied
+pub type Almanac = (Seeds, Vec<Map>);
 
+/// One `<name> map:` section, like `seed-to-soil`
 #[derive(Eq, PartialEq, Default, Debug)]
 pub struct Map {
+    /// Like `seed-to-soil`
     pub name: String,
+    /// Numbers outside all of these map to themselves
     pub ranges: Vec<RangeMap>,
 }
 
 impl Map {
+    /// A map with no ranges yet
     pub fn new(name: String) -> Map {
         Map {
             name,
@@ -84,6 +130,7 @@ impl Map {
         }
     }
 
+    /// Where `number` ends up
     pub fn destination_for(&self, number: usize) -> usize {
         let special_mapping = self
             .ranges
@@ -98,22 +145,64 @@ impl Map {
             None => number,
         }
     }
+
+    /// Where every number in `ranges` ends up, as ranges again. Like `destination_for`, the first
+    /// range map containing a number is the one that maps it.
+    pub fn destination_ranges(&self, ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+        let mut unmapped = ranges.to_vec();
+        let mut mapped = vec![];
+
+        for range_map in self.ranges.iter() {
+            let source_start = *range_map.source_range.start();
+            let source_end = *range_map.source_range.end();
+            let destination_start = *range_map.destination_range.start();
+            let mut still_unmapped = vec![];
+
+            for range in unmapped {
+                // The parts before, inside and after this range map's source range
+                let overlap = range.start.max(source_start)..range.end.min(source_end + 1);
+                if overlap.is_empty() {
+                    still_unmapped.push(range);
+                    continue;
+                }
+
+                mapped.push(
+                    destination_start + (overlap.start - source_start)
+                        ..destination_start + (overlap.end - source_start),
+                );
+                still_unmapped.push(range.start..overlap.start);
+                still_unmapped.push(overlap.end..range.end);
+            }
+
+            unmapped = still_unmapped
+                .into_iter()
+                .filter(|r| !r.is_empty())
+                .collect();
+        }
+
+        mapped.extend(unmapped);
+        mapped
+    }
 }
 
+/// A `<destination start> <source start> <length>` line
 #[derive(Eq, PartialEq, Debug)]
 pub struct RangeMap {
+    /// The numbers this maps
     pub source_range: RangeInclusive<usize>,
+    /// The same length as `source_range`
     pub destination_range: RangeInclusive<usize>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{get_input, Input};
+    use crate::FIXTURES;
+    use aoc_core::Input;
 
     #[test]
     fn test_parse_input() {
-        let (seeds, maps) = parse_input(&get_input::<String>(Input::Test1));
+        let (seeds, maps) = parse_input(&FIXTURES.get_input(Input::Test(1)).unwrap()).unwrap();
         assert_eq!(seeds, vec![79, 14, 55, 13]);
         assert_eq!(
             maps[0],
@@ -151,7 +240,7 @@ mod tests {
 
     #[test]
     fn test_map_destination_for() {
-        let (_seeds, maps) = parse_input(&get_input::<String>(Input::Test1));
+        let (_seeds, maps) = parse_input(&FIXTURES.get_input(Input::Test(1)).unwrap()).unwrap();
         assert_eq!(maps[0].destination_for(0), 0);
         assert_eq!(maps[0].destination_for(1), 1);
         assert_eq!(maps[0].destination_for(49), 49);
@@ -162,4 +251,31 @@ mod tests {
         assert_eq!(maps[0].destination_for(98), 50);
         assert_eq!(maps[0].destination_for(99), 51);
     }
+
+    #[test]
+    fn test_map_destination_ranges() {
+        let (_seeds, maps) = parse_input(&FIXTURES.get_input(Input::Test(1)).unwrap()).unwrap();
+        let mut ranges = maps[0].destination_ranges(&[40..60, 95..100]);
+        ranges.sort_by_key(|r| r.start);
+        assert_eq!(ranges, vec![40..50, 50..52, 52..62, 97..100]);
+    }
+
+    #[test]
+    fn test_parse_input_error() {
+        let parse =
+            |text: &str| parse_input(&text.split('\n').map(String::from).collect::<Vec<_>>());
+
+        assert_eq!(
+            parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50"),
+            Err(ParseError::new(1, "3 numbers").with_line(5, "52 50"))
+        );
+        assert_eq!(
+            parse("seeds: 79 1x"),
+            Err(ParseError::new(11, "a seed number").with_line(1, "seeds: 79 1x"))
+        );
+        assert_eq!(
+            parse("seeds: 79 14\n\nseed-to-soil map:"),
+            Err(ParseError::new(1, "a range").with_line(4, ""))
+        );
+    }
 }
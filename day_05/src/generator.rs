@@ -0,0 +1,53 @@
+use aoc_core::Rng;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+// `size` pairs (at least one) of seed numbers (which part 2 reads as ranges of up to 100 seeds),
+// then the 7 maps, each with 1 to 5 ranges whose sources don't overlap
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let max = 1000 * size.max(1);
+    let seeds: Vec<String> = (0..size.max(1))
+        .flat_map(|_| [rng.range(0..max), rng.range(1..101)])
+        .map(|n| n.to_string())
+        .collect();
+
+    let mut sections = vec![format!("seeds: {}", seeds.join(" "))];
+    for name in MAPS {
+        // Sorted, distinct breakpoints, with each range running from one to the next
+        let mut breakpoints: Vec<usize> = (0..rng.range(2..7)).map(|_| rng.range(0..max)).collect();
+        breakpoints.sort_unstable();
+        breakpoints.dedup();
+
+        let mut lines = vec![format!("{} map:", name)];
+        for window in breakpoints.windows(2) {
+            let (source, length) = (window[0], window[1] - window[0]);
+            lines.push(format!("{} {} {}", rng.range(0..max), source, length));
+        }
+        if lines.len() == 1 {
+            lines.push(format!("{} {} {}", rng.range(0..max), rng.range(0..max), 1));
+        }
+
+        rng.shuffle(&mut lines[1..]);
+        sections.push(lines.join("\n"));
+    }
+
+    sections.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day05;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day05>(0..20, 10);
+    }
+}
@@ -0,0 +1,161 @@
+//! Day 5: If You Give A Seed A Fertilizer
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{parse_whole, trace, Fixtures, ParseError, Rng, Solution};
+pub use data::*;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Almanac;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        parse_whole(input, |lines| parse_input(&lines))
+    }
+
+    fn part_1(almanac: Almanac) -> usize {
+        part_1(almanac)
+    }
+
+    fn part_2(almanac: Almanac) -> usize {
+        part_2(almanac)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// What is the lowest location number that corresponds to any of the initial seed numbers?
+pub fn part_1((seeds, maps): Almanac) -> usize {
+    let mut lowest_location = usize::MAX;
+    trace!(
+        Summary,
+        "Mapping {} seeds through {} maps",
+        seeds.len(),
+        maps.len()
+    );
+
+    for seed in seeds.iter() {
+        let location = maps.iter().fold(*seed, |n, map| {
+            let destination = map.destination_for(n);
+            trace!(Detail, "{}: {} -> {}", map.name, n, destination);
+            destination
+        });
+        trace!(Steps, "Seed {} ends up at location {}", seed, location);
+        lowest_location = lowest_location.min(location);
+    }
+
+    trace!(Summary, "The lowest location is {}", lowest_location);
+    lowest_location
+}
+
+/// It looks like the first line actually describes ranges of seed numbers. Each pair of numbers
+/// represents a (start, length) of seed numbers.
+///
+/// There are billions of seeds, so rather than map each one, map whole ranges at once: each map
+/// splits a range wherever its range maps start and end.
+pub fn part_2((seeds, maps): Almanac) -> usize {
+    let seed_ranges = seeds
+        .chunks_exact(2)
+        .map(|p| p[0]..p[0] + p[1])
+        .collect::<Vec<_>>();
+
+    trace!(
+        Summary,
+        "Mapping {} ranges ({} seeds) through {} maps",
+        seed_ranges.len(),
+        seed_ranges.iter().map(|range| range.len()).sum::<usize>(),
+        maps.len()
+    );
+
+    let location_ranges = maps.iter().fold(seed_ranges, |ranges, map| {
+        let ranges = map.destination_ranges(&ranges);
+        trace!(Steps, "{}: now {} ranges", map.name, ranges.len());
+        ranges
+    });
+
+    let lowest_location = location_ranges
+        .iter()
+        .filter(|range| !range.is_empty())
+        .map(|range| range.start)
+        .min()
+        .unwrap();
+
+    trace!(Summary, "The lowest location is {}", lowest_location);
+    lowest_location
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{check_oracle, Rng};
+
+    // Every seed, one at a time
+    fn part_2_brute_force((seeds, maps): &Almanac) -> usize {
+        seeds
+            .chunks_exact(2)
+            .flat_map(|pair| pair[0]..pair[0] + pair[1])
+            .map(|seed| maps.iter().fold(seed, |n, map| map.destination_for(n)))
+            .min()
+            .unwrap()
+    }
+
+    // Seed ranges as (start, length), and maps of (destination start, source start, length) range
+    // maps. Those are kept small so the brute force stays quick, and may overlap: the first one
+    // wins.
+    type SmallAlmanac = (Vec<(usize, usize)>, Vec<Vec<(usize, usize, usize)>>);
+
+    fn almanac((seeds, maps): &SmallAlmanac) -> Almanac {
+        let seeds = seeds
+            .iter()
+            .flat_map(|(start, len)| [*start, *len])
+            .collect();
+        let maps = maps
+            .iter()
+            .map(|range_maps| Map {
+                name: "map".to_string(),
+                ranges: range_maps
+                    .iter()
+                    .filter(|(_, _, len)| *len > 0)
+                    .map(|(destination, source, len)| RangeMap {
+                        source_range: *source..=source + len - 1,
+                        destination_range: *destination..=destination + len - 1,
+                    })
+                    .collect(),
+            })
+            .collect();
+        (seeds, maps)
+    }
+
+    #[test]
+    fn test_part_2_oracle() {
+        let generate = |rng: &mut Rng| -> SmallAlmanac {
+            let mut number = || rng.range(0..100);
+            let seeds = (0..3).map(|_| (number(), number() + 1)).collect();
+            let maps = (0..4)
+                .map(|_| (0..3).map(|_| (number(), number(), number())).collect())
+                .collect();
+            (seeds, maps)
+        };
+        // Answers are `None` once shrinking has emptied out every seed range
+        let has_seeds = |input: &SmallAlmanac| input.0.iter().any(|(_, len)| *len > 0);
+
+        check_oracle(
+            0..300,
+            generate,
+            |input| has_seeds(input).then(|| part_2_brute_force(&almanac(input))),
+            |input| has_seeds(input).then(|| part_2(almanac(input))),
+        );
+    }
+}
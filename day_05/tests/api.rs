@@ -0,0 +1,15 @@
+// Uses the day the way another crate would, through its public API
+use aoc_core::{parse_lines, Input};
+
+#[test]
+fn test_public_api() {
+    let text = day_05::FIXTURES.text(&Input::Test(1)).unwrap();
+    let lines: Vec<String> = parse_lines(&text).unwrap();
+
+    let (seeds, maps) = day_05::parse_input(&lines).unwrap();
+    assert_eq!(seeds, vec![79, 14, 55, 13]);
+    assert_eq!(maps[0].destination_for(79), 81);
+
+    assert_eq!(day_05::part_1(day_05::parse_input(&lines).unwrap()), 35);
+    assert_eq!(day_05::part_2((seeds, maps)), 46);
+}
@@ -1,10 +1,16 @@
+use aoc_core::ParseError;
+
+/// One column of the `Time:` and `Distance:` lines
 #[derive(Eq, PartialEq, Debug)]
 pub struct Race {
+    /// How long the race lasts
     pub time_ms: usize,
+    /// The distance to beat
     pub distance_record_mm: usize,
 }
 
 impl Race {
+    /// How far the boat goes if the button is held for `charge_time_ms`
     pub fn distance_for_charge(&self, charge_time_ms: usize) -> usize {
         if charge_time_ms >= self.time_ms {
             0
@@ -15,20 +21,82 @@ impl Race {
         }
     }
 
+    /// The distance is a downward parabola peaking at half the race, so the winning charge times
+    /// are one unbroken run around the middle. The quadratic formula gives its ends, which are then
+    /// nudged past any floating point error.
     pub fn ways_to_beat(&self) -> usize {
-        let mut ways_to_beat = 0;
+        let beats =
+            |charge_time_ms| self.distance_for_charge(charge_time_ms) > self.distance_record_mm;
+
+        let middle = self.time_ms / 2;
+        if !beats(middle) {
+            return 0;
+        }
+
+        let time = self.time_ms as f64;
+        let half_width = (time * time - 4.0 * self.distance_record_mm as f64)
+            .max(0.0)
+            .sqrt()
+            / 2.0;
+
+        let mut first = ((time / 2.0 - half_width).max(0.0) as usize).min(middle);
+        while first > 0 && beats(first - 1) {
+            first -= 1;
+        }
+        while !beats(first) {
+            first += 1;
+        }
 
-        for charge_time in 0..=self.time_ms {
-            let distance = self.distance_for_charge(charge_time);
-            if distance > self.distance_record_mm {
-                ways_to_beat += 1;
-            }
+        let mut last = ((time / 2.0 + half_width) as usize).clamp(middle, self.time_ms);
+        while last < self.time_ms && beats(last + 1) {
+            last += 1;
+        }
+        while !beats(last) {
+            last -= 1;
         }
 
-        ways_to_beat
+        last - first + 1
     }
 }
 
+/// Checks for a `Time:` line and a `Distance:` line with the same number of numbers, which still fit
+/// in a usize once part 2 joins each line's digits together
+pub fn check_lines(lines: &[String]) -> Result<(), ParseError> {
+    let mut counts = vec![];
+
+    for (idx, label) in ["Time:", "Distance:"].into_iter().enumerate() {
+        let line = lines.get(idx).map_or("", String::as_str);
+        let error = |err: ParseError| err.with_line(idx + 1, line);
+
+        let numbers = line
+            .strip_prefix(label)
+            .ok_or_else(|| error(ParseError::new(1, format!("`{}`", label))))?;
+        for n in numbers.split_whitespace() {
+            n.parse::<usize>()
+                .map_err(|_| error(ParseError::at(line, n, "a number")))?;
+        }
+        numbers.replace(' ', "").parse::<usize>().map_err(|_| {
+            error(ParseError::new(
+                label.len() + 1,
+                "numbers that fit in a usize",
+            ))
+        })?;
+
+        counts.push(numbers.split_whitespace().count());
+    }
+
+    if counts[0] != counts[1] {
+        let err = ParseError::new(1, format!("{} distances, one per race", counts[0]));
+        return Err(err.with_line(2, &lines[1]));
+    }
+    if let Some(line) = lines.get(2) {
+        return Err(ParseError::new(1, "the end of the input").with_line(3, line));
+    }
+
+    Ok(())
+}
+
+/// A race per column of the `Time:` and `Distance:` lines
 pub fn build_races(lines: &[String]) -> Vec<Race> {
     let parse_ints = |s: &String| -> Vec<usize> {
         s.split_whitespace()
@@ -52,11 +120,19 @@ pub fn build_races(lines: &[String]) -> Vec<Race> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{get_input, Input};
+    use crate::FIXTURES;
+    use aoc_core::{check_oracle, Input, Rng};
+
+    // Tries every charge time
+    fn ways_to_beat_brute_force(race: &Race) -> usize {
+        (0..=race.time_ms)
+            .filter(|charge_time| race.distance_for_charge(*charge_time) > race.distance_record_mm)
+            .count()
+    }
 
     #[test]
     fn test_distance_for_charge() {
-        let races = build_races(&get_input::<String>(Input::Test1));
+        let races = build_races(&FIXTURES.get_input::<String>(Input::Test(1)).unwrap());
         assert_eq!(races[0].distance_for_charge(0), 0);
         assert_eq!(races[0].distance_for_charge(1), 6);
         assert_eq!(races[0].distance_for_charge(2), 10);
@@ -70,7 +146,7 @@ mod tests {
     #[test]
     fn test_build_races() {
         assert_eq!(
-            build_races(&get_input::<String>(Input::Test1)),
+            build_races(&FIXTURES.get_input::<String>(Input::Test(1)).unwrap()),
             vec![
                 Race {
                     time_ms: 7,
@@ -87,4 +163,47 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_check_lines() {
+        let check =
+            |text: &str| check_lines(&text.split('\n').map(String::from).collect::<Vec<_>>());
+
+        assert_eq!(check("Time: 7 15\nDistance: 9 40"), Ok(()));
+        assert_eq!(
+            check("Time: 7 15"),
+            Err(ParseError::new(1, "`Distance:`").with_line(2, ""))
+        );
+        assert_eq!(
+            check("Time: 7 1x\nDistance: 9 40"),
+            Err(ParseError::new(9, "a number").with_line(1, "Time: 7 1x"))
+        );
+        assert_eq!(
+            check("Time: 7\nDistance: 9 40"),
+            Err(ParseError::new(1, "1 distances, one per race").with_line(2, "Distance: 9 40"))
+        );
+        assert_eq!(
+            check("Time: 99999999999 99999999999\nDistance: 9 40"),
+            Err(ParseError::new(6, "numbers that fit in a usize")
+                .with_line(1, "Time: 99999999999 99999999999"))
+        );
+    }
+
+    #[test]
+    fn test_ways_to_beat_oracle() {
+        let race = |(time_ms, distance_record_mm): &(usize, usize)| Race {
+            time_ms: *time_ms,
+            distance_record_mm: *distance_record_mm,
+        };
+
+        check_oracle(
+            0..500,
+            |rng: &mut Rng| {
+                let time_ms = rng.range(0..2000);
+                (time_ms, rng.range(0..time_ms * time_ms / 4 + 2))
+            },
+            |input| ways_to_beat_brute_force(&race(input)),
+            |input| race(input).ways_to_beat(),
+        );
+    }
 }
@@ -0,0 +1,35 @@
+use aoc_core::Rng;
+
+// `size` races of up to 99 ms, each with a record that can be beaten. Part 2 joins their digits
+// into one long race, and its record (up to 4 digits a race) only fits in a usize for up to 4
+// races, so that's as many as there are. There's always at least one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut times = vec![];
+    let mut records = vec![];
+
+    for _ in 0..size.clamp(1, 4) {
+        let time = rng.range(2..100);
+        // Holding the button for half the race goes the farthest
+        let best = (time / 2) * (time - time / 2);
+        times.push(time);
+        records.push(rng.range(0..best));
+    }
+
+    let line = |name: &str, numbers: &[usize]| {
+        let numbers: Vec<String> = numbers.iter().map(|n| format!("{:>5}", n)).collect();
+        format!("{:<9}{}", name, numbers.concat())
+    };
+    [line("Time:", &times), line("Distance:", &records)].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day06;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day06>(0..20, 3);
+        // The size `aoc gen` uses by default
+        aoc_core::check_generator::<Day06>(0..20, 10);
+    }
+}
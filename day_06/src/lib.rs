@@ -0,0 +1,82 @@
+//! Day 6: Wait For It
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{parse_whole, trace, Fixtures, ParseError, Rng, Solution};
+pub use data::*;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Vec<String>;
+    type Answer = usize;
+
+    // The parts build their races from the lines differently; this just makes sure that they can
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_whole(input, |lines| {
+            check_lines(&lines)?;
+            Ok(lines)
+        })
+    }
+
+    fn part_1(lines: Vec<String>) -> usize {
+        part_1(lines)
+    }
+
+    fn part_2(lines: Vec<String>) -> usize {
+        part_2(lines)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// Determine the number of ways to beat the record in each race. Multiply these numbers together.
+pub fn part_1(lines: Vec<String>) -> usize {
+    let product = build_races(&lines)
+        .iter()
+        .map(|race| {
+            let ways = race.ways_to_beat();
+            trace!(
+                Steps,
+                "A {} ms race with a {} mm record can be won {} ways",
+                race.time_ms,
+                race.distance_record_mm,
+                ways
+            );
+            ways
+        })
+        .product();
+
+    trace!(Summary, "The ways to win multiply to {}", product);
+    product
+}
+
+/// There's really only one race - ignore the spaces between the numbers on each line. How many ways
+/// can you beat the record in this one much longer race?
+pub fn part_2(lines: Vec<String>) -> usize {
+    let fixed_time_line = lines[0].replace("Time:", "").replace(' ', "");
+    let fixed_distance_line = lines[1].replace("Distance:", "").replace(' ', "");
+    let race = build_races(&[fixed_time_line, fixed_distance_line])
+        .pop()
+        .unwrap();
+
+    let ways = race.ways_to_beat();
+    trace!(
+        Summary,
+        "The {} ms race with a {} mm record can be won {} ways",
+        race.time_ms,
+        race.distance_record_mm,
+        ways
+    );
+    ways
+}
@@ -1,27 +1,42 @@
+use aoc_core::ParseError;
 use std::collections::HashMap;
 use std::str::FromStr;
 use HandType::*;
 
+/// A `<cards> <bid>` line
 #[derive(Eq, PartialEq, Debug)]
 pub struct Hand {
+    /// The cards, in the order they were dealt
     pub cards: [char; 5],
+    /// Each card's strength, for breaking ties (2 is 2, A is 14)
     pub card_values: [usize; 5],
+    /// The hand's type, with `J` as a jack
     pub hand_type: HandType,
+    /// What the hand wins, times its rank
     pub bid: usize,
 }
 
+/// Strongest first
 #[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug)]
 pub enum HandType {
+    /// Like `AAAAA`
     FiveOfAKind,
+    /// Like `AA8AA`
     FourOfAKind,
+    /// Like `23332`
     FullHouse,
+    /// Like `TTT98`
     ThreeOfAKind,
+    /// Like `23432`
     TwoPair,
+    /// Like `A23A4`
     OnePair,
+    /// Like `23456`
     HighCard,
 }
 
 impl Hand {
+    /// The best type this hand can be with its `J`s as jokers
     pub fn j_wildcard_hand_type(&self) -> HandType {
         let j_count = self.cards.iter().filter(|c| **c == 'J').count();
 
@@ -62,11 +77,24 @@ impl Hand {
 }
 
 impl FromStr for Hand {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let cards: [char; 5] = s.chars().take(5).collect::<Vec<_>>().try_into().unwrap();
-        let bid = s.chars().skip(6).collect::<String>().parse().unwrap();
+        let cards: [char; 5] = s
+            .chars()
+            .take(5)
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| ParseError::new(1, "a hand of 5 cards"))?;
+        if s.chars().nth(5) != Some(' ') {
+            return Err(ParseError::new(6, "a space"));
+        }
+        let bid = s
+            .chars()
+            .skip(6)
+            .collect::<String>()
+            .parse()
+            .map_err(|_| ParseError::new(7, "a bid"))?;
 
         let card_counts = cards.iter().fold(HashMap::new(), |mut counts, card| {
             *counts.entry(card).or_insert(0) += 1;
@@ -100,14 +128,18 @@ impl FromStr for Hand {
             HighCard
         };
 
-        let card_values = cards.map(|c| match c {
-            'A' => 14,
-            'K' => 13,
-            'Q' => 12,
-            'J' => 11,
-            'T' => 10,
-            _ => c.to_digit(10).unwrap() as usize,
-        });
+        let mut card_values = [0; 5];
+        for (idx, c) in cards.iter().enumerate() {
+            card_values[idx] = match c {
+                'A' => 14,
+                'K' => 13,
+                'Q' => 12,
+                'J' => 11,
+                'T' => 10,
+                '2'..='9' => c.to_digit(10).unwrap() as usize,
+                _ => return Err(ParseError::new(idx + 1, "a card (A, K, Q, J, T or 2-9)")),
+            };
+        }
 
         Ok(Hand {
             cards,
@@ -121,12 +153,13 @@ impl FromStr for Hand {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{get_input, Input};
+    use crate::FIXTURES;
+    use aoc_core::Input;
 
     #[test]
     fn test_hand_from_str() {
         assert_eq!(
-            get_input::<Hand>(Input::Test1)[0],
+            FIXTURES.get_input::<Hand>(Input::Test(1)).unwrap()[0],
             Hand {
                 cards: ['3', '2', 'T', '3', 'K'],
                 card_values: [3, 2, 10, 3, 13],
@@ -135,4 +168,25 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_hand_from_str_error() {
+        assert_eq!(
+            "32T3K 7x5".parse::<Hand>(),
+            Err(ParseError::new(7, "a bid"))
+        );
+        assert_eq!(
+            "32X3K 765".parse::<Hand>(),
+            Err(ParseError::new(3, "a card (A, K, Q, J, T or 2-9)"))
+        );
+        assert_eq!(
+            "32T".parse::<Hand>(),
+            Err(ParseError::new(1, "a hand of 5 cards"))
+        );
+        assert_eq!(
+            "32T3KX765".parse::<Hand>(),
+            Err(ParseError::new(6, "a space"))
+        );
+        assert_eq!("32T3K".parse::<Hand>(), Err(ParseError::new(6, "a space")));
+    }
 }
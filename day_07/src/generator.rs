@@ -0,0 +1,32 @@
+use aoc_core::Rng;
+
+const CARDS: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+// `size` hands (at least one) with bids up to 1000. Cards are drawn from a few at a time, so pairs
+// and better come up often.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..size.max(1) {
+        let mut cards = CARDS;
+        rng.shuffle(&mut cards);
+        let drawn_from = &cards[..rng.range(1..6)];
+
+        let hand: String = (0..5).map(|_| *rng.choose(drawn_from)).collect();
+        lines.push(format!("{} {}", hand, rng.range(1..1001)));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day07;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day07>(0..20, 50);
+    }
+}
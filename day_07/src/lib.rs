@@ -0,0 +1,86 @@
+//! Day 7: Camel Cards
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{parse_lines, trace, Fixtures, ParseError, Rng, Solution};
+pub use data::*;
+use std::cmp::Reverse;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Vec<Hand>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_1(hands: Vec<Hand>) -> usize {
+        part_1(hands)
+    }
+
+    fn part_2(hands: Vec<Hand>) -> usize {
+        part_2(hands)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// Each hand wins an amount equal to its bid multiplied by its rank, where the weakest hand gets
+/// rank 1. What are the total winnings?
+pub fn part_1(mut hands: Vec<Hand>) -> usize {
+    hands.sort_unstable_by_key(|hand| Reverse((hand.hand_type, Reverse(hand.card_values))));
+    total_winnings(&hands)
+}
+
+/// Now, J cards are jokers - wildcards that can act like whatever card would make the hand the
+/// strongest type possible. For the purpose of breaking ties, J is still treated as J. J is now also
+/// the weakest card. What are the new total winnings?
+pub fn part_2(mut hands: Vec<Hand>) -> usize {
+    // Change all J values from 11 to 1
+    hands.iter_mut().for_each(|hand| {
+        hand.card_values.iter_mut().for_each(|c| {
+            if *c == 11 {
+                *c = 1
+            }
+        })
+    });
+
+    hands.sort_unstable_by_key(|hand| {
+        Reverse((hand.j_wildcard_hand_type(), Reverse(hand.card_values)))
+    });
+
+    total_winnings(&hands)
+}
+
+fn total_winnings(hands: &[Hand]) -> usize {
+    let winnings = hands
+        .iter()
+        .enumerate()
+        .map(|(idx, hand)| {
+            trace!(
+                Steps,
+                "Rank {}: {} wins {} * {}",
+                idx + 1,
+                hand.cards.iter().collect::<String>(),
+                idx + 1,
+                hand.bid
+            );
+            (idx + 1) * hand.bid
+        })
+        .sum();
+
+    trace!(Summary, "{} hands win {} in total", hands.len(), winnings);
+    winnings
+}
@@ -1,34 +1,79 @@
+use aoc_core::ParseError;
+
+/// A node's three letter name
 pub type NodeRef<'a> = &'a str;
 
+/// A `AAA = (BBB, CCC)` line
 #[derive(Eq, PartialEq, Hash, Clone, Debug)]
 pub struct Node<'a> {
+    /// The node this line describes
     pub name: NodeRef<'a>,
+    /// Where an `L` instruction goes
     pub left: NodeRef<'a>,
+    /// Where an `R` instruction goes
     pub right: NodeRef<'a>,
 }
 
-pub fn parse_node(s: &str) -> Node {
-    Node {
-        name: &s[0..=2],
-        left: &s[7..=9],
-        right: &s[12..=14],
+/// Parses a line like `AAA = (BBB, CCC)`
+pub fn parse_node(s: &str) -> Result<Node<'_>, ParseError> {
+    fn name(s: &str, start: usize) -> Result<&str, ParseError> {
+        s.get(start..(start + 3))
+            .filter(|name| name.chars().all(|c| c.is_ascii_alphanumeric()))
+            .ok_or_else(|| ParseError::new(start + 1, "a 3-character node name"))
+    }
+
+    fn literal(s: &str, start: usize, literal: &str) -> Result<(), ParseError> {
+        match s.get(start..) {
+            Some(rest) if rest.starts_with(literal) => Ok(()),
+            _ => Err(ParseError::new(start + 1, format!("`{}`", literal))),
+        }
+    }
+
+    let node = Node {
+        name: name(s, 0)?,
+        left: literal(s, 3, " = (").and_then(|_| name(s, 7))?,
+        right: literal(s, 10, ", ").and_then(|_| name(s, 12))?,
+    };
+    literal(s, 15, ")")?;
+
+    if s.len() > 16 {
+        return Err(ParseError::new(17, "the end of the line"));
     }
+
+    Ok(node)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{get_input, Input};
+    use crate::FIXTURES;
+    use aoc_core::Input;
 
     #[test]
     fn test_node_from_str() {
         assert_eq!(
-            parse_node(&get_input::<String>(Input::Test1)[3]),
-            Node {
+            parse_node(&FIXTURES.get_input::<String>(Input::Test(1)).unwrap()[3]),
+            Ok(Node {
                 name: "BBB",
                 left: "AAA",
                 right: "ZZZ",
-            }
+            })
+        );
+    }
+
+    #[test]
+    fn test_node_from_str_error() {
+        assert_eq!(
+            parse_node("BBB = (AAA ZZZ)"),
+            Err(ParseError::new(11, "`, `"))
+        );
+        assert_eq!(
+            parse_node("BBB = (AAA, Z)"),
+            Err(ParseError::new(13, "a 3-character node name"))
+        );
+        assert_eq!(
+            parse_node("BBB = (AAA, ZZZ))"),
+            Err(ParseError::new(17, "the end of the line"))
         );
     }
 }
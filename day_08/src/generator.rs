@@ -0,0 +1,73 @@
+use aoc_core::Rng;
+
+// Roughly `size` nodes, split between up to 6 paths that each run from a node ending in A to one
+// ending in Z (the first being AAA to ZZZ), then loop. Every node leads on down its path in one
+// direction, and either does the same or stays put in the other, so every path gets to its Z.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let paths = (1 + size / 25).min(6);
+    let path_len = (size / paths).max(2);
+    let letter = |n: usize| (b'B' + (n % 24) as u8) as char;
+
+    let mut instructions: Vec<char> = vec!['L', 'R'];
+    instructions.extend((0..rng.range(0..10)).map(|_| *rng.choose(&['L', 'R'])));
+    rng.shuffle(&mut instructions);
+
+    let mut next_name = 0;
+    let mut lines = vec![];
+    for path in 0..paths {
+        let prefix = match path {
+            0 => "AA".to_string(),
+            _ => format!("{}{}", letter(path / 24), letter(path)),
+        };
+
+        // Names in the middle only use B-Y, so they never clash with the starts and ends
+        let mut names = vec![format!("{}A", prefix)];
+        for _ in 0..path_len - 2 {
+            let n = next_name;
+            next_name += 1;
+            names.push(format!(
+                "{}{}{}",
+                letter(n / 576),
+                letter(n / 24),
+                letter(n)
+            ));
+        }
+        names.push(match path {
+            0 => "ZZZ".to_string(),
+            _ => format!("{}Z", prefix),
+        });
+
+        for (idx, name) in names.iter().enumerate() {
+            let next = match names.get(idx + 1) {
+                Some(next) => next,
+                // The end loops back to the second node
+                None => &names[1],
+            };
+            let other = if rng.chance(0.5) { next } else { name };
+
+            let (left, right) = if rng.chance(0.5) {
+                (next, other)
+            } else {
+                (other, next)
+            };
+            lines.push(format!("{} = ({}, {})", name, left, right));
+        }
+    }
+
+    rng.shuffle(&mut lines);
+    format!(
+        "{}\n\n{}",
+        instructions.iter().collect::<String>(),
+        lines.join("\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day08;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day08>(0..20, 100);
+    }
+}
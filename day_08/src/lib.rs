@@ -0,0 +1,237 @@
+//! Day 8: Haunted Wasteland
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{parse_whole, trace, Fixtures, ParseError, Rng, Solution};
+pub use data::*;
+use std::collections::HashMap;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Vec<String>;
+    type Answer = usize;
+
+    // The parsed nodes borrow from the lines, so the parts parse them again; this just makes sure
+    // that they will parse.
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_whole(input, |lines| {
+            parse_data(&lines)?;
+            Ok(lines)
+        })
+    }
+
+    fn part_1(lines: Vec<String>) -> usize {
+        part_1(lines)
+    }
+
+    fn part_2(lines: Vec<String>) -> usize {
+        part_2(lines)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// Start at AAA, and follow the left/right instructions. How many steps are required to reach ZZZ?
+pub fn part_1(lines: Vec<String>) -> usize {
+    let (instructions, nodes) = parse_data(&lines).unwrap();
+
+    let mut instruction_count = 0;
+    let mut current_node = &nodes["AAA"];
+
+    loop {
+        let instruction = instructions[instruction_count % instructions.len()];
+        current_node = match instruction {
+            'L' => &nodes[&current_node.left],
+            'R' => &nodes[&current_node.right],
+            _ => unreachable!(),
+        };
+
+        instruction_count += 1;
+
+        trace!(Detail, "{} -> {}", instruction, current_node.name);
+
+        if current_node.name == "ZZZ" {
+            trace!(
+                Summary,
+                "Reached ZZZ from AAA in {} steps",
+                instruction_count
+            );
+            return instruction_count;
+        }
+    }
+}
+
+/// Start at every node that ends with A and follow all paths at the same time until they all
+/// simultaneously end up at nodes that end with Z. How many steps?
+pub fn part_2(lines: Vec<String>) -> usize {
+    let (instructions, nodes) = parse_data(&lines).unwrap();
+
+    let mut instruction_count = 0;
+    let mut current_nodes = nodes
+        .iter()
+        .filter_map(|(node_ref, node)| match node_ref.ends_with('A') {
+            true => Some(node),
+            false => None,
+        })
+        .collect::<Vec<&Node>>();
+
+    trace!(
+        Summary,
+        "Following {} paths from the nodes ending in A",
+        current_nodes.len()
+    );
+
+    // HashMap<node name, (has cycled, instruction count)
+    let mut cycle_counts = current_nodes
+        .iter()
+        .map(|node| ((**node).clone(), (false, 0)))
+        .collect::<HashMap<Node, _>>();
+
+    loop {
+        let instruction = instructions[instruction_count % instructions.len()];
+        for node in current_nodes.iter_mut() {
+            *node = match instruction {
+                'L' => &nodes[&node.left],
+                'R' => &nodes[&node.right],
+                _ => unreachable!(),
+            };
+        }
+
+        instruction_count += 1;
+
+        for (idx, (_node, (has_cycled, cycle_instruction_count))) in
+            cycle_counts.iter_mut().enumerate()
+        {
+            let current_node = current_nodes[idx];
+
+            if !*has_cycled {
+                *cycle_instruction_count += 1;
+
+                if current_node.name.ends_with('Z') {
+                    trace!(
+                        Steps,
+                        "A path reached {} after {} steps",
+                        current_node.name,
+                        cycle_instruction_count
+                    );
+                    *has_cycled = true;
+                }
+            }
+        }
+
+        let all_have_cycled = cycle_counts.iter().all(|(_, (has_cycled, _))| *has_cycled);
+        if all_have_cycled {
+            break;
+        }
+    }
+
+    let steps = cycle_counts
+        .values()
+        .map(|(_has_cycled, ins_count)| *ins_count)
+        .fold(1, num::integer::lcm);
+
+    trace!(
+        Summary,
+        "The paths all line up after {} steps, the LCM of their cycles",
+        steps
+    );
+    steps
+}
+
+/// The L/R instructions, and each node by name
+pub type Network<'a> = (Vec<char>, HashMap<NodeRef<'a>, Node<'a>>);
+
+/// Parses the instructions line, then a node per line after the blank one. Every node a left or
+/// right leads to must be defined, and so must AAA, where part 1 starts.
+pub fn parse_data(lines: &[String]) -> Result<Network<'_>, ParseError> {
+    let instructions = lines[0].chars().collect::<Vec<_>>();
+
+    if let Some(idx) = instructions.iter().position(|c| *c != 'L' && *c != 'R') {
+        let err = ParseError::new(idx + 1, "an instruction (L or R)");
+        return Err(err.with_line(1, &lines[0]));
+    } else if instructions.is_empty() {
+        return Err(ParseError::new(1, "L/R instructions").with_line(1, &lines[0]));
+    }
+
+    match lines.get(1) {
+        Some(line) if !line.is_empty() => {
+            return Err(ParseError::new(1, "a blank line").with_line(2, line));
+        }
+        _ => {}
+    }
+
+    let parsed = lines
+        .iter()
+        .enumerate()
+        .skip(2)
+        .map(|(idx, line)| {
+            parse_node(line)
+                .map(|node| (idx + 1, line, node))
+                .map_err(|err| err.with_line(idx + 1, line))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let nodes = parsed
+        .iter()
+        .map(|(_, _, node)| (node.name, node.clone()))
+        .collect::<HashMap<_, _>>();
+
+    for (line_number, line, node) in &parsed {
+        for target in [node.left, node.right] {
+            if !nodes.contains_key(target) {
+                let err = ParseError::at(line, target, "the name of a node in the network");
+                return Err(err.with_line(*line_number, line));
+            }
+        }
+    }
+
+    if !nodes.contains_key("AAA") {
+        let err = ParseError::new(1, "a node named AAA");
+        return Err(err.with_line(lines.len() + 1, ""));
+    }
+
+    Ok((instructions, nodes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_data_error() {
+        let lines = |text: &str| text.split('\n').map(String::from).collect::<Vec<_>>();
+        assert_eq!(
+            parse_data(&lines("LR\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)")).err(),
+            Some(ParseError::new(1, "a blank line").with_line(2, "AAA = (BBB, BBB)"))
+        );
+        assert_eq!(
+            parse_data(&lines("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)")).err(),
+            Some(
+                ParseError::new(13, "the name of a node in the network")
+                    .with_line(4, "BBB = (AAA, ZZZ)")
+            )
+        );
+        assert_eq!(
+            parse_data(&lines("LR\n\nBBB = (ZZZ, BBB)\nZZZ = (ZZZ, ZZZ)")).err(),
+            Some(ParseError::new(1, "a node named AAA").with_line(5, ""))
+        );
+    }
+
+    #[test]
+    fn test_parse_error_after_blank_line() {
+        assert_eq!(
+            Day08::parse("\nLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZ)\n").err(),
+            Some(ParseError::new(13, "a 3-character node name").with_line(5, "BBB = (AAA, ZZ)"))
+        );
+    }
+}
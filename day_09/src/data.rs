@@ -1,32 +1,47 @@
+use aoc_core::ParseError;
 use std::str::FromStr;
 
+/// A line of readings
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub struct History {
+    /// Oldest first
     pub values: Vec<isize>,
 }
 
 impl FromStr for History {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(History {
-            values: s.split_whitespace().map(|v| v.parse().unwrap()).collect(),
-        })
+        let values = s
+            .split_whitespace()
+            .map(|v| v.parse().map_err(|_| ParseError::at(s, v, "an integer")))
+            .collect::<Result<_, _>>()?;
+
+        Ok(History { values })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{get_input, Input};
+    use crate::FIXTURES;
+    use aoc_core::Input;
 
     #[test]
     fn test_thing_from_str() {
         assert_eq!(
-            get_input::<History>(Input::Test1)[0],
+            FIXTURES.get_input::<History>(Input::Test(1)).unwrap()[0],
             History {
                 values: vec![0, 3, 6, 9, 12, 15]
             }
         );
     }
+
+    #[test]
+    fn test_history_from_str_error() {
+        assert_eq!(
+            "0 3 six 9".parse::<History>(),
+            Err(ParseError::new(5, "an integer"))
+        );
+    }
 }
@@ -0,0 +1,33 @@
+use aoc_core::Rng;
+
+// `size` histories of 6 to 21 values, each a polynomial of degree 0 to 4 with small coefficients,
+// so they always reduce to zeros
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..size {
+        let coefficients: Vec<isize> = (0..rng.range(1..6))
+            .map(|_| rng.signed_range(-5..6))
+            .collect();
+        let values: Vec<String> = (0..rng.range(6..22) as isize)
+            .map(|x| {
+                let value = coefficients.iter().rev().fold(0, |acc, c| acc * x + c);
+                value.to_string()
+            })
+            .collect();
+
+        lines.push(values.join(" "));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day09;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day09>(0..20, 50);
+    }
+}
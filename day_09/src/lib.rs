@@ -0,0 +1,117 @@
+//! Day 9: Mirage Maintenance
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{parse_lines, trace, Fixtures, ParseError, Rng, Solution};
+pub use data::*;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Vec<History>;
+    type Answer = isize;
+
+    fn parse(input: &str) -> Result<Vec<History>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_1(histories: Vec<History>) -> isize {
+        part_1(histories)
+    }
+
+    fn part_2(histories: Vec<History>) -> isize {
+        part_2(histories)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// Extrapolate the next value for each history. What is the sum of these extrapolated values?
+pub fn part_1(mut histories: Vec<History>) -> isize {
+    extrapolate(&mut histories);
+    let sum = histories.iter().map(|h| h.values.last().unwrap()).sum();
+
+    trace!(Summary, "{} next values add up to {}", histories.len(), sum);
+    sum
+}
+
+/// Extrapolate backwards; what is the sum of these extrapolated values?
+pub fn part_2(mut histories: Vec<History>) -> isize {
+    histories.iter_mut().for_each(|h| h.values.reverse());
+    extrapolate(&mut histories);
+    histories.iter_mut().for_each(|h| h.values.reverse());
+    let sum = histories.iter().map(|h| h.values.first().unwrap()).sum();
+
+    trace!(
+        Summary,
+        "{} previous values add up to {}",
+        histories.len(),
+        sum
+    );
+    sum
+}
+
+fn extrapolate(histories: &mut [History]) {
+    for orig_history in histories.iter_mut() {
+        let mut extrapolated = vec![orig_history.clone()];
+        let mut current_history = orig_history.clone();
+
+        // Iteratively "reduce" the history until the value change is all zero
+        while !current_history.values.iter().all(|v| *v == 0) {
+            let mut next_history = History {
+                values: Vec::with_capacity(current_history.values.len()),
+            };
+
+            let mut values_iter = current_history.values.iter().peekable();
+            while let Some(v1) = values_iter.next() {
+                if let Some(v2) = values_iter.peek() {
+                    next_history.values.push(*v2 - v1);
+                }
+            }
+
+            extrapolated.push(next_history.clone());
+            current_history = next_history;
+        }
+
+        trace!(
+            Steps,
+            "{:?} reduces to zeros in {} steps",
+            orig_history.values,
+            extrapolated.len() - 1
+        );
+        for (depth, history) in extrapolated.iter().enumerate() {
+            trace!(Detail, "{}{:?}", "  ".repeat(depth), history.values);
+        }
+
+        // Do the actual extrapolation, working from the zero-change history back up
+        let mut previous_extrap = None;
+        for history in extrapolated.iter_mut().rev() {
+            if let Some(prev) = previous_extrap {
+                let extrap_value = prev + history.values.last().unwrap();
+                history.values.push(extrap_value);
+                previous_extrap = Some(extrap_value);
+            } else {
+                history.values.push(0);
+                previous_extrap = Some(0);
+            }
+        }
+
+        // Swap out the original history for the now-extrapolated top-level History
+        *orig_history = extrapolated.remove(0);
+        trace!(
+            Steps,
+            "Extrapolated to {}",
+            orig_history.values.last().unwrap()
+        );
+    }
+}
@@ -1,181 +1,153 @@
-use std::collections::BTreeMap;
+use aoc_core::{Dir, Grid, ParseError, Pos};
 use PipeType::*;
 
-pub type Pipes = BTreeMap<Node, PipeType>;
-
+/// A field of pipes, one of which is the start
 #[derive(Eq, PartialEq, Clone, Debug)]
-pub struct Grid {
-    pub pipes: Pipes,
-    pub start_pipe: Node,
-    pub rows: usize,
-    pub cols: usize,
+pub struct Maze {
+    /// `None` for ground; the start tile has whichever pipe fits
+    pub pipes: Grid<Option<PipeType>>,
+    /// Where the `S` was
+    pub start_pipe: Pos,
 }
 
-impl Grid {
-    pub fn build(lines: &[String]) -> Grid {
-        let mut pipes = BTreeMap::new();
-        let mut start_pipe = None;
-
-        let rows = lines.len();
-        let cols = lines[0].len();
-
-        for (row, line) in lines.iter().enumerate() {
-            for (col, char) in line.chars().enumerate() {
-                let node = Node {
-                    row: row as isize,
-                    col: col as isize,
-                };
-
-                let pipe_type = match char {
-                    '|' => Vertical,
-                    '-' => Horizontal,
-                    'L' => UpRightBend,
-                    'J' => UpLeftBend,
-                    '7' => DownLeftBend,
-                    'F' => DownRightBend,
-                    'S' => {
-                        start_pipe = Some(node);
-                        continue;
-                    }
-                    _ => continue,
-                };
-
-                pipes.insert(node, pipe_type);
-            }
-        }
+impl Maze {
+    /// Parses the maze, working out which pipe is under the `S`
+    pub fn build(lines: &[String]) -> Result<Maze, ParseError> {
+        let tiles = Grid::parse(lines, "a pipe, `.` or `S`", |char| {
+            "|-LJ7F.S".contains(char).then_some(char)
+        })?;
+
+        let start_pipe = tiles
+            .iter()
+            .find(|(_pos, char)| **char == 'S')
+            .map(|(pos, _char)| pos)
+            .ok_or_else(|| ParseError::new(1, "a starting tile `S`"))?;
+
+        let mut pipes = tiles.map(|char| match char {
+            '|' => Some(Vertical),
+            '-' => Some(Horizontal),
+            'L' => Some(UpRightBend),
+            'J' => Some(UpLeftBend),
+            '7' => Some(DownLeftBend),
+            'F' => Some(DownRightBend),
+            _ => None,
+        });
 
         // Figure out the Start pipe's type
-        let start_pipe = start_pipe.unwrap();
-        pipes.insert(start_pipe, Grid::start_pipe_type(&pipes, &start_pipe));
-
-        Grid {
-            pipes,
-            start_pipe,
-            rows,
-            cols,
-        }
-    }
-
-    pub fn start_pipe_type(pipes: &Pipes, start_pipe: &Node) -> PipeType {
-        let mut connected = vec![];
-
-        if let Some(up) = pipes.get(&start_pipe.up()) {
-            if up.goes_down() {
-                connected.push(start_pipe.up());
-            }
-        }
-
-        if let Some(down) = pipes.get(&start_pipe.down()) {
-            if down.goes_up() {
-                connected.push(start_pipe.down());
-            }
-        }
-
-        if let Some(left) = pipes.get(&start_pipe.left()) {
-            if left.goes_right() {
-                connected.push(start_pipe.left());
-            }
-        }
-
-        if let Some(right) = pipes.get(&start_pipe.right()) {
-            if right.goes_left() {
-                connected.push(start_pipe.right());
-            }
-        }
+        let start_pipe_type = Maze::start_pipe_type(&pipes, start_pipe).ok_or_else(|| {
+            ParseError::new(start_pipe.col + 1, "a start connected to exactly two pipes")
+                .with_line(start_pipe.row + 1, &lines[start_pipe.row])
+        })?;
+        pipes[start_pipe] = Some(start_pipe_type);
 
-        if connected == Grid::connected_nodes(start_pipe, Vertical) {
-            Vertical
-        } else if connected == Grid::connected_nodes(start_pipe, Horizontal) {
-            Horizontal
-        } else if connected == Grid::connected_nodes(start_pipe, UpRightBend) {
-            UpRightBend
-        } else if connected == Grid::connected_nodes(start_pipe, UpLeftBend) {
-            UpLeftBend
-        } else if connected == Grid::connected_nodes(start_pipe, DownLeftBend) {
-            DownLeftBend
-        } else if connected == Grid::connected_nodes(start_pipe, DownRightBend) {
-            DownRightBend
-        } else {
-            panic!();
-        }
+        Ok(Maze { pipes, start_pipe })
     }
 
-    pub fn connected_nodes(node: &Node, pipe_type: PipeType) -> [Node; 2] {
-        match pipe_type {
-            Vertical => [node.up(), node.down()],
-            Horizontal => [node.left(), node.right()],
-            UpRightBend => [node.up(), node.right()],
-            UpLeftBend => [node.up(), node.left()],
-            DownLeftBend => [node.down(), node.left()],
-            DownRightBend => [node.down(), node.right()],
-        }
+    /// The pipe connecting the two pipes that lead into `start_pipe`, if exactly two do
+    pub fn start_pipe_type(pipes: &Grid<Option<PipeType>>, start_pipe: Pos) -> Option<PipeType> {
+        let connected: Vec<Dir> = Dir::ALL
+            .into_iter()
+            .filter(|dir| {
+                pipes
+                    .step(start_pipe, *dir)
+                    .and_then(|pos| pipes[pos])
+                    .is_some_and(|pipe| pipe.goes(dir.opposite()))
+            })
+            .collect();
+
+        PipeType::ALL
+            .into_iter()
+            .find(|pipe_type| pipe_type.directions() == connected[..])
     }
 
-    pub fn connected_pipes(&self, node: &Node) -> [Node; 2] {
-        Grid::connected_nodes(node, self.pipes[node])
-    }
-}
-
-#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
-pub struct Node {
-    pub row: isize,
-    pub col: isize,
-}
-
-impl Node {
-    pub fn up(&self) -> Node {
-        Node {
-            row: self.row - 1,
-            col: self.col,
-        }
-    }
+    /// The (up to two) nodes a pipe leads to
+    pub fn connected_pipes(&self, node: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let directions = self.pipes[node].map(|pipe| pipe.directions());
 
-    pub fn down(&self) -> Node {
-        Node {
-            row: self.row + 1,
-            col: self.col,
-        }
-    }
-
-    pub fn left(&self) -> Node {
-        Node {
-            row: self.row,
-            col: self.col - 1,
-        }
-    }
-
-    pub fn right(&self) -> Node {
-        Node {
-            row: self.row,
-            col: self.col + 1,
-        }
+        directions
+            .into_iter()
+            .flatten()
+            .filter_map(move |dir| self.pipes.step(node, dir))
     }
 }
 
+/// A tile with a pipe on it, joining two of its sides
 #[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
 pub enum PipeType {
+    /// `|`
     Vertical,
+    /// `-`
     Horizontal,
+    /// `L`
     UpRightBend,
+    /// `J`
     UpLeftBend,
+    /// `7`
     DownLeftBend,
+    /// `F`
     DownRightBend,
 }
 
 impl PipeType {
-    pub fn goes_up(&self) -> bool {
-        matches!(self, Vertical | UpRightBend | UpLeftBend)
+    /// Every type of pipe
+    pub const ALL: [PipeType; 6] = [
+        Vertical,
+        Horizontal,
+        UpRightBend,
+        UpLeftBend,
+        DownLeftBend,
+        DownRightBend,
+    ];
+
+    /// In the same order as `Dir::ALL`
+    pub fn directions(&self) -> [Dir; 2] {
+        match self {
+            Vertical => [Dir::Up, Dir::Down],
+            Horizontal => [Dir::Left, Dir::Right],
+            UpRightBend => [Dir::Up, Dir::Right],
+            UpLeftBend => [Dir::Up, Dir::Left],
+            DownLeftBend => [Dir::Down, Dir::Left],
+            DownRightBend => [Dir::Down, Dir::Right],
+        }
     }
 
-    pub fn goes_down(&self) -> bool {
-        matches!(self, Vertical | DownLeftBend | DownRightBend)
+    /// Whether the pipe has an end facing `dir`
+    pub fn goes(&self, dir: Dir) -> bool {
+        self.directions().contains(&dir)
     }
+}
 
-    pub fn goes_left(&self) -> bool {
-        matches!(self, Horizontal | UpLeftBend | DownLeftBend)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FIXTURES;
+    use aoc_core::Input;
+
+    #[test]
+    fn test_maze_build() {
+        let maze = Maze::build(&FIXTURES.get_input(Input::Test(1)).unwrap()).unwrap();
+        assert_eq!(maze.start_pipe, Pos::new(2, 0));
+        assert_eq!(maze.pipes[maze.start_pipe], Some(DownRightBend));
+        assert_eq!(
+            maze.connected_pipes(maze.start_pipe).collect::<Vec<_>>(),
+            vec![Pos::new(3, 0), Pos::new(2, 1)]
+        );
     }
 
-    pub fn goes_right(&self) -> bool {
-        matches!(self, Horizontal | UpRightBend | DownRightBend)
+    #[test]
+    fn test_maze_build_error() {
+        let lines = |text: &str| text.split('\n').map(String::from).collect::<Vec<_>>();
+        assert_eq!(
+            Maze::build(&lines(".....\n.S-7.\n.|.|.\n.L-J.\n.....")).map(|m| m.start_pipe),
+            Ok(Pos::new(1, 1))
+        );
+        assert_eq!(
+            Maze::build(&lines(".S-7.\n.|.x.")),
+            Err(ParseError::new(4, "a pipe, `.` or `S`").with_line(2, ".|.x."))
+        );
+        assert_eq!(
+            Maze::build(&lines(".S...\n.....")),
+            Err(ParseError::new(2, "a start connected to exactly two pipes").with_line(1, ".S..."))
+        );
     }
 }
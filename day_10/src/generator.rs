@@ -0,0 +1,174 @@
+use aoc_core::{Dir, Grid, Pos, Rng};
+use std::collections::BTreeMap;
+
+const JUNK: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+// A `size` x `size` field with one loop, plus junk pipes. The loop traces around a random tree:
+// the tree grows over part of a coarse grid (a third of the field's width), each of its nodes is
+// drawn as a 2x2 block of cells with its branches 2 cells wide and a gap between neighbouring
+// blocks, and the loop runs along the outside edges of the drawn cells. Every block's middle
+// corner is inside the loop, and the loop gets longer with `size`. The field keeps a free border
+// around the loop, and the coarse cells the tree didn't reach are free space too.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let coarse = (size.saturating_sub(2) / 3).max(1);
+    let mut drawn = Grid::new(3 * coarse - 1, 3 * coarse - 1, false);
+    let mut draw = |row: usize, col: usize, rows: usize, cols: usize| {
+        for row in row..row + rows {
+            for col in col..col + cols {
+                drawn[Pos::new(row, col)] = true;
+            }
+        }
+    };
+
+    // Randomized depth-first search over the coarse grid, until the tree has between a third and
+    // two thirds of its nodes
+    let coarse_grid = Grid::new(coarse, coarse, ());
+    let mut visited = Grid::new(coarse, coarse, false);
+    let nodes = coarse * coarse;
+    let mut unplaced = rng.range((nodes / 3).max(1)..(2 * nodes / 3).max(1) + 1);
+    let start = Pos::new(rng.range(0..coarse), rng.range(0..coarse));
+    let mut stack = vec![start];
+    visited[start] = true;
+    draw(start.row * 3, start.col * 3, 2, 2);
+    unplaced -= 1;
+
+    while let Some(&node) = stack.last() {
+        if unplaced == 0 {
+            break;
+        }
+        let unvisited: Vec<Pos> = coarse_grid
+            .neighbours_4(node)
+            .filter(|next| !visited[*next])
+            .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let next = *rng.choose(&unvisited);
+        visited[next] = true;
+        draw(next.row * 3, next.col * 3, 2, 2);
+        // The branch fills the gap between the two blocks
+        let (top, left) = (node.row.min(next.row) * 3, node.col.min(next.col) * 3);
+        if node.row == next.row {
+            draw(top, left + 2, 2, 1);
+        } else {
+            draw(top + 2, left, 1, 2);
+        }
+        stack.push(next);
+        unplaced -= 1;
+    }
+
+    // The outside edges of the drawn cells, as the directions leaving each corner. Corners are
+    // numbered like cells, with corner (r, c) at the top left of cell (r, c).
+    let mut corners: BTreeMap<Pos, Vec<Dir>> = BTreeMap::new();
+    for (cell, _) in drawn.iter().filter(|(_, drawn)| **drawn) {
+        let Pos { row, col } = cell;
+        let edges = [
+            (Dir::Up, Pos::new(row, col), Pos::new(row, col + 1)),
+            (
+                Dir::Down,
+                Pos::new(row + 1, col),
+                Pos::new(row + 1, col + 1),
+            ),
+            (Dir::Left, Pos::new(row, col), Pos::new(row + 1, col)),
+            (
+                Dir::Right,
+                Pos::new(row, col + 1),
+                Pos::new(row + 1, col + 1),
+            ),
+        ];
+
+        for (side, from, to) in edges {
+            if drawn.step(cell, side).is_some_and(|next| drawn[next]) {
+                continue;
+            }
+            let (away, back) = match side {
+                Dir::Up | Dir::Down => (Dir::Right, Dir::Left),
+                Dir::Left | Dir::Right => (Dir::Down, Dir::Up),
+            };
+            corners.entry(from).or_default().push(away);
+            corners.entry(to).or_default().push(back);
+        }
+    }
+
+    // Corners span one more row and column than the cells; they're moved in by one for the border,
+    // and the rest of the field is padded out to `size`
+    let len = (3 * coarse + 2).max(size);
+    let mut field = Grid::new(len, len, '.');
+    let corners: BTreeMap<Pos, Vec<Dir>> = corners
+        .into_iter()
+        .map(|(corner, dirs)| (Pos::new(corner.row + 1, corner.col + 1), dirs))
+        .collect();
+    for (corner, dirs) in corners.iter() {
+        field[*corner] = pipe(dirs);
+    }
+
+    // Junk pipes everywhere off the loop, except right next to the start where they'd look connected
+    let loop_corners: Vec<&Pos> = corners.keys().collect();
+    let start = **rng.choose(&loop_corners);
+    for pos in field.positions().collect::<Vec<_>>() {
+        let near_start = pos.row.abs_diff(start.row) + pos.col.abs_diff(start.col) <= 1;
+        if !corners.contains_key(&pos) && !near_start && rng.chance(0.3) {
+            field[pos] = *rng.choose(&JUNK);
+        }
+    }
+    field[start] = 'S';
+
+    field
+        .iter_rows()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn pipe(dirs: &[Dir]) -> char {
+    let goes = |dir| dirs.contains(&dir);
+    match (
+        goes(Dir::Up),
+        goes(Dir::Down),
+        goes(Dir::Left),
+        goes(Dir::Right),
+    ) {
+        (true, true, _, _) => '|',
+        (_, _, true, true) => '-',
+        (true, _, _, true) => 'L',
+        (true, _, true, _) => 'J',
+        (_, true, true, _) => '7',
+        _ => 'F',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part_1, part_2, Day10};
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day10>(0..20, 20);
+    }
+
+    #[test]
+    fn test_generate_varies() {
+        let answers = (0..10)
+            .map(|seed| {
+                let input = generate(&mut Rng::new(seed), 20);
+                let maze = || Day10::parse(&input).unwrap();
+                (part_1(maze()), part_2(maze()))
+            })
+            .collect::<Vec<_>>();
+
+        assert!(
+            answers.iter().all(|(_, enclosed)| *enclosed > 0),
+            "{:?}",
+            answers
+        );
+        assert!(
+            answers.iter().any(|answer| *answer != answers[0]),
+            "{:?}",
+            answers
+        );
+    }
+}
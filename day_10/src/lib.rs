@@ -0,0 +1,233 @@
+//! Day 10: Pipe Maze
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{
+    parse_whole, trace, Fixtures, Frame, Highlight, ParseError, Part, Pos, Rng, Solution,
+};
+pub use data::*;
+use std::collections::{HashMap, HashSet};
+use PipeType::*;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Maze;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Maze, ParseError> {
+        parse_whole(input, |lines| Maze::build(&lines))
+    }
+
+    fn part_1(maze: Maze) -> usize {
+        part_1(maze)
+    }
+
+    fn part_2(maze: Maze) -> usize {
+        part_2(maze)
+    }
+
+    fn render(maze: Maze, part: Part) -> Option<Frame> {
+        Some(render(maze, part))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// How many steps along the loop does it take to get from the starting position to farthest point from the start?
+pub fn part_1(maze: Maze) -> usize {
+    let mut loop_pipes: HashMap<Pos, usize> = HashMap::new();
+    let mut current_pipes: Vec<Pos> = Vec::with_capacity(2);
+    let mut step_count = 0;
+
+    // Starting location
+    loop_pipes.insert(maze.start_pipe, 0);
+    current_pipes.push(maze.start_pipe);
+
+    // Travel around loop
+    while !current_pipes.is_empty() {
+        let mut next_pipes = Vec::with_capacity(2);
+
+        for pipe in current_pipes.iter() {
+            loop_pipes.insert(*pipe, step_count);
+
+            next_pipes.append(
+                &mut maze
+                    .connected_pipes(*pipe)
+                    .filter(|n| !loop_pipes.contains_key(n))
+                    .collect(),
+            );
+        }
+
+        trace!(
+            Detail,
+            "Step {}: {} pipes reached",
+            step_count,
+            loop_pipes.len()
+        );
+        current_pipes = next_pipes;
+        step_count += 1;
+    }
+
+    trace!(
+        Summary,
+        "The loop from the start at row {}, column {} is {} pipes long; the farthest is {} steps away",
+        maze.start_pipe.row + 1,
+        maze.start_pipe.col + 1,
+        loop_pipes.len(),
+        step_count - 1
+    );
+    step_count - 1
+}
+
+/// How many tiles are enclosed by the loop?
+pub fn part_2(maze: Maze) -> usize {
+    let pipes_in_loop = find_loop(&maze);
+    trace!(Summary, "The loop is {} pipes long", pipes_in_loop.len());
+
+    let enclosed = enclosed_nodes(&maze, &pipes_in_loop).len();
+    trace!(
+        Summary,
+        "Casting rays from the left edge, {} tiles are inside the loop",
+        enclosed
+    );
+    enclosed
+}
+
+fn find_loop(maze: &Maze) -> HashSet<Pos> {
+    let mut pipes_in_loop = HashSet::new();
+
+    // Travel loop to build a set of nodes that are actually part of the loop, since there are "junk" bits of pipe
+    let mut current_pipe = Some(maze.start_pipe);
+    while let Some(pipe) = current_pipe {
+        pipes_in_loop.insert(pipe);
+
+        let next_pipe = maze
+            .connected_pipes(pipe)
+            .find(|n| !pipes_in_loop.contains(n));
+
+        current_pipe = next_pipe;
+    }
+
+    pipes_in_loop
+}
+
+fn enclosed_nodes(maze: &Maze, pipes_in_loop: &HashSet<Pos>) -> HashSet<Pos> {
+    let mut nodes_on_inside = HashSet::new();
+    let mut nodes_on_outside = HashSet::new();
+
+    // Raytrace each node to the "outside". If the ray crosses an odd number of loop pipe segments, the point is inside
+    // the loop.
+    for row in 0..maze.pipes.rows() {
+        for col in 0..maze.pipes.cols() {
+            let node = Pos::new(row, col);
+
+            // Skip tracing to nodes that ARE part of the loop
+            if pipes_in_loop.contains(&node) {
+                continue;
+            }
+
+            // Count the number of pipes crossed from a ray coming in from left to right. Only count pipes that when
+            // traversed, actually enter or exit you from the loop. For example:
+            //
+            //   F-----7*     Tracing to * never actually enters the loop
+            //   |  *  |      Tracing to * enters & never exits the loop
+            //   |  F--J*     Tracing to * enters the loop at | and exits at J
+            //   |  |*        Tracing to * enters and exists the loop
+            //   L--J*        Tracing to * never actually enters the loop, but we count as enter + exit so it's fine
+            let mut pipe_walls_crossed = 0;
+            let ray_row = row;
+            for ray_col in 0..col {
+                let ray_node = Pos::new(ray_row, ray_col);
+
+                // While tracing, disregard nodes NOT part of the loop
+                if !pipes_in_loop.contains(&ray_node) {
+                    continue;
+                }
+
+                // Only count vertical, "L", and "J" pipes
+                pipe_walls_crossed += match maze.pipes[ray_node] {
+                    Some(Vertical | UpRightBend | UpLeftBend) => 1,
+                    _ => 0,
+                };
+            }
+
+            let on_inside = pipe_walls_crossed % 2 == 1;
+            trace!(
+                Detail,
+                "Row {}, column {}: crossed {} walls, so it's {}",
+                row + 1,
+                col + 1,
+                pipe_walls_crossed,
+                if on_inside { "inside" } else { "outside" }
+            );
+            if on_inside {
+                nodes_on_inside.insert(node);
+            } else {
+                nodes_on_outside.insert(node);
+            }
+        }
+    }
+
+    nodes_on_inside
+}
+
+// The loop, with the start in red, and for part 2 the tiles it encloses
+fn render(maze: Maze, part: Part) -> Frame {
+    let pipes_in_loop = find_loop(&maze);
+    let mut frame = Frame::new(maze.pipes.map(|pipe| match pipe {
+        Some(Vertical) => '│',
+        Some(Horizontal) => '─',
+        Some(UpRightBend) => '└',
+        Some(UpLeftBend) => '┘',
+        Some(DownLeftBend) => '┐',
+        Some(DownRightBend) => '┌',
+        None => '.',
+    }));
+
+    for pipe in pipes_in_loop.iter() {
+        frame.highlight(*pipe, Highlight::Yellow);
+    }
+    if part == Part::Two {
+        for node in enclosed_nodes(&maze, &pipes_in_loop) {
+            frame.cells[node] = '*';
+            frame.highlight(node, Highlight::Green);
+        }
+    }
+    frame.highlight(maze.start_pipe, Highlight::Red);
+
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Input;
+
+    #[test]
+    fn test_render() {
+        let maze = Maze::build(&FIXTURES.get_input(Input::Test(3)).unwrap()).unwrap();
+        let frame = render(maze, Part::Two);
+        let count = |highlight| {
+            frame
+                .highlights
+                .iter()
+                .filter(|(_, h)| **h == Some(highlight))
+                .count()
+        };
+
+        assert_eq!(count(Highlight::Green), 8);
+        assert_eq!(count(Highlight::Red), 1);
+        assert_eq!(frame.cells[Pos::new(4, 12)], '┌');
+    }
+}
@@ -1,59 +1,61 @@
-use std::collections::BTreeSet;
+use aoc_core::{Grid, ParseError, Pos};
 
-#[derive(Eq, PartialEq, Default, Debug)]
+/// The image of the universe, before it expands
+#[derive(Eq, PartialEq, Debug)]
 pub struct SpaceMap {
-    pub galaxies: BTreeSet<Coord>,
-    pub rows: usize,
-    pub cols: usize,
+    /// `true` for a galaxy
+    pub image: Grid<bool>,
 }
 
 impl SpaceMap {
-    pub fn build(lines: &[String]) -> SpaceMap {
-        let mut map = SpaceMap {
-            rows: lines.len(),
-            cols: lines[0].len(),
-            ..Default::default()
-        };
-
-        for (row, line) in lines.iter().enumerate() {
-            for (col, char) in line.chars().enumerate() {
-                if char == '#' {
-                    map.galaxies.insert(Coord { row, col });
-                }
-            }
-        }
-
-        map
+    /// Parses an image of `#` galaxies and `.` space
+    pub fn build(lines: &[String]) -> Result<SpaceMap, ParseError> {
+        let image = Grid::parse(lines, "`#` or `.`", |char| match char {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(SpaceMap { image })
     }
 
-    // Any rows or columns that contain no galaxies should all actually be twice as big
-    pub fn expanded(&self, factor: usize) -> SpaceMap {
-        let expand_by = factor - 1;
-        let mut new_map = SpaceMap::default();
-
-        let mut empty_rows: BTreeSet<usize> = (0..self.rows).collect();
-        let mut empty_cols: BTreeSet<usize> = (0..self.cols).collect();
-
-        for galaxy in self.galaxies.iter() {
-            empty_rows.remove(&galaxy.row);
-            empty_cols.remove(&galaxy.col);
-        }
-
-        for galaxy in self.galaxies.iter() {
-            let empty_rows_above = empty_rows.iter().filter(|&&r| r < galaxy.row).count();
-            let empty_cols_to_left = empty_cols.iter().filter(|&&c| c < galaxy.col).count();
-            new_map.galaxies.insert(Coord {
-                row: galaxy.row + empty_rows_above * expand_by,
-                col: galaxy.col + empty_cols_to_left * expand_by,
-            });
-        }
-
-        new_map
+    /// Where each galaxy is, row by row
+    pub fn galaxies(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.image
+            .iter()
+            .filter(|(_pos, galaxy)| **galaxy)
+            .map(|(pos, _galaxy)| pos)
+    }
+
+    /// Rows without a galaxy
+    pub fn empty_rows(&self) -> Vec<usize> {
+        (0..self.image.rows())
+            .filter(|row| !self.image.row(*row).contains(&true))
+            .collect()
     }
-}
 
-#[derive(Eq, PartialEq, Ord, PartialOrd, Debug)]
-pub struct Coord {
-    pub row: usize,
-    pub col: usize,
+    /// Columns without a galaxy
+    pub fn empty_cols(&self) -> Vec<usize> {
+        (0..self.image.cols())
+            .filter(|col| !self.image.column(*col).any(|galaxy| *galaxy))
+            .collect()
+    }
+
+    /// Any rows or columns that contain no galaxies should all actually be twice as big
+    pub fn expanded_galaxies(&self, factor: usize) -> Vec<Pos> {
+        let expand_by = factor - 1;
+        let empty_rows = self.empty_rows();
+        let empty_cols = self.empty_cols();
+
+        self.galaxies()
+            .map(|galaxy| {
+                let empty_rows_above = empty_rows.iter().filter(|&&r| r < galaxy.row).count();
+                let empty_cols_to_left = empty_cols.iter().filter(|&&c| c < galaxy.col).count();
+                Pos::new(
+                    galaxy.row + empty_rows_above * expand_by,
+                    galaxy.col + empty_cols_to_left * expand_by,
+                )
+            })
+            .collect()
+    }
 }
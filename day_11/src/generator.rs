@@ -0,0 +1,33 @@
+use aoc_core::Rng;
+
+// A `size` x `size` image with some galaxies, leaving about a fifth of the rows and columns empty
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.2)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.chance(0.2)).collect();
+
+    (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|col| {
+                    let galaxy = !empty_rows[row] && !empty_cols[col] && rng.chance(0.1);
+                    if galaxy {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day11;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day11>(0..20, 30);
+    }
+}
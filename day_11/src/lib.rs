@@ -0,0 +1,125 @@
+//! Day 11: Cosmic Expansion
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{
+    parse_whole, trace, Fixtures, Frame, Highlight, ParseError, Part, Pos, Rng, Solution,
+};
+pub use data::*;
+use itertools::Itertools;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = SpaceMap;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<SpaceMap, ParseError> {
+        parse_whole(input, |lines| SpaceMap::build(&lines))
+    }
+
+    fn part_1(map: SpaceMap) -> usize {
+        part_1(map)
+    }
+
+    fn part_2(map: SpaceMap) -> usize {
+        part_2(map)
+    }
+
+    fn render(map: SpaceMap, _part: Part) -> Option<Frame> {
+        Some(render(&map))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// Expand the universe, then find the shortest path between every pair of galaxies. What is the sum of these lengths?
+pub fn part_1(map: SpaceMap) -> usize {
+    sum_of_expanded_galaxy_pair_lengths(&map, 2)
+}
+
+/// Same, but expand empty rows/cols by 1 million
+pub fn part_2(map: SpaceMap) -> usize {
+    sum_of_expanded_galaxy_pair_lengths(&map, 1_000_000)
+}
+
+fn sum_of_expanded_galaxy_pair_lengths(map: &SpaceMap, expand_by: usize) -> usize {
+    let galaxies = map.expanded_galaxies(expand_by);
+    trace!(
+        Summary,
+        "Expanding {} empty rows and {} empty columns {} times",
+        map.empty_rows().len(),
+        map.empty_cols().len(),
+        expand_by
+    );
+    for (galaxy, expanded) in map.galaxies().zip(galaxies.iter()) {
+        trace!(
+            Steps,
+            "Galaxy at row {}, column {} moves to row {}, column {}",
+            galaxy.row + 1,
+            galaxy.col + 1,
+            expanded.row + 1,
+            expanded.col + 1
+        );
+    }
+
+    let sum = galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(g1, g2)| g1.row.abs_diff(g2.row) + g1.col.abs_diff(g2.col))
+        .sum();
+
+    trace!(
+        Summary,
+        "The {} pairs of {} galaxies are {} apart in total",
+        galaxies.len() * galaxies.len().saturating_sub(1) / 2,
+        galaxies.len(),
+        sum
+    );
+    sum
+}
+
+// The galaxies, and the empty rows and columns that expand
+fn render(map: &SpaceMap) -> Frame {
+    let mut frame = Frame::new(map.image.map(|galaxy| if *galaxy { '#' } else { '.' }));
+
+    for row in map.empty_rows() {
+        for col in 0..map.image.cols() {
+            frame.highlight(Pos::new(row, col), Highlight::Blue);
+        }
+    }
+    for col in map.empty_cols() {
+        for row in 0..map.image.rows() {
+            frame.highlight(Pos::new(row, col), Highlight::Blue);
+        }
+    }
+    for galaxy in map.galaxies() {
+        frame.highlight(galaxy, Highlight::Yellow);
+    }
+
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Input;
+
+    #[test]
+    fn test_sum_of_expanded_galaxy_pair_lengths() {
+        let map = SpaceMap::build(&FIXTURES.get_input(Input::Test(1)).unwrap()).unwrap();
+        assert_eq!(sum_of_expanded_galaxy_pair_lengths(&map, 2), 374);
+        assert_eq!(sum_of_expanded_galaxy_pair_lengths(&map, 10), 1030);
+        assert_eq!(sum_of_expanded_galaxy_pair_lengths(&map, 100), 8410);
+    }
+}
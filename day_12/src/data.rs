@@ -1,52 +1,60 @@
+use aoc_core::ParseError;
 use itertools::Itertools;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use Spring::*;
 
-type Springs = Vec<Spring>;
-type DamagedRuns = Vec<usize>;
+/// A row of springs, left to right
+pub type Springs = Vec<Spring>;
+/// The sizes of each contiguous group of damaged springs, left to right
+pub type DamagedRuns = Vec<usize>;
 
+/// A `<springs> <damaged runs>` line, like `???.### 1,1,3`
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub struct SpringRow {
-    springs: Springs,
-    damaged_runs: Vec<usize>,
+    /// The springs' conditions, some of them unknown
+    pub springs: Springs,
+    /// The runs of damaged springs there really are
+    pub damaged_runs: DamagedRuns,
 }
 
 impl SpringRow {
-    pub fn unknown_idxs(&self) -> Vec<usize> {
-        self.springs
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, spring)| (*spring == Unknown).then_some(idx))
-            .collect()
-    }
-
+    /// Counted a spring at a time from the end: `ways[idx][run]` is how many arrangements of
+    /// `springs[idx..]` make up exactly `damaged_runs[run..]`.
     pub fn possible_arrangements(&self) -> usize {
-        let mut count = 0;
-        let mut springs = self.springs.clone();
+        let (springs, runs) = (&self.springs, &self.damaged_runs);
+        let mut ways = vec![vec![0; runs.len() + 1]; springs.len() + 2];
+        ways[springs.len()][runs.len()] = 1;
+        ways[springs.len() + 1][runs.len()] = 1;
 
-        // Foolishly brute-force through every possible manifestation of the Unknown springs
-        for damaged_spring_idxs in self.unknown_idxs().into_iter().powerset() {
-            // Set this combination's springs to Damaged
-            damaged_spring_idxs
-                .iter()
-                .for_each(|idx| springs[*idx] = Damaged);
+        for idx in (0..springs.len()).rev() {
+            for run in 0..=runs.len() {
+                let mut count = 0;
 
-            if SpringRow::matches(&springs, &self.damaged_runs) {
-                count += 1;
-            }
+                if springs[idx] != Damaged {
+                    count += ways[idx + 1][run];
+                }
 
-            // Restore previous state
-            damaged_spring_idxs
-                .iter()
-                .for_each(|idx| springs[*idx] = Unknown);
+                // A damaged run starting here has to fit, and can't run straight into another
+                if springs[idx] != Operational && run < runs.len() && runs[run] > 0 {
+                    let end = idx + runs[run];
+                    let fits = end <= springs.len()
+                        && !springs[idx..end].contains(&Operational)
+                        && springs.get(end) != Some(&Damaged);
+                    if fits {
+                        count += ways[end + 1][run + 1];
+                    }
+                }
+
+                ways[idx][run] = count;
+            }
         }
 
-        count
+        ways[0][0]
     }
 
-    // Replace the list of spring conditions with five copies of itself (separated by Unknown) and replace the list of
-    // damaged spring runs with five copies of itself.
-    #[allow(dead_code)]
+    /// Replace the list of spring conditions with five copies of itself (separated by Unknown) and replace the list of
+    /// damaged spring runs with five copies of itself.
     pub fn unfold(&self) -> SpringRow {
         let mut springs = Vec::with_capacity(self.springs.len() * 5 + 5);
 
@@ -66,6 +74,7 @@ impl SpringRow {
         }
     }
 
+    /// Whether `springs` (with nothing unknown) has exactly these runs of damaged springs
     pub fn matches(springs: &Springs, real_damaged_runs: &DamagedRuns) -> bool {
         let mut damaged_runs = vec![];
         let mut current_run: Option<usize> = None;
@@ -97,27 +106,31 @@ impl SpringRow {
 }
 
 impl FromStr for SpringRow {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let springs = s
+        let (springs_str, counts_str) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(s.len() + 1, "a space, then damaged spring counts"))?;
+
+        let springs = springs_str
             .chars()
-            .take_while(|&c| c != ' ')
-            .map(|c| match c {
-                '.' => Operational,
-                '#' => Damaged,
-                '?' => Unknown,
-                _ => panic!("Unknown spring: '{}'", c),
+            .enumerate()
+            .map(|(idx, c)| match c {
+                '.' => Ok(Operational),
+                '#' => Ok(Damaged),
+                '?' => Ok(Unknown),
+                _ => Err(ParseError::new(idx + 1, "a spring (., # or ?)")),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        let damaged_counts = s
-            .split_whitespace()
-            .last()
-            .unwrap()
+        let damaged_counts = counts_str
             .split(',')
-            .map(|n| n.parse().unwrap())
-            .collect();
+            .map(|n| {
+                n.parse()
+                    .map_err(|_| ParseError::at(s, n, "a damaged spring count"))
+            })
+            .collect::<Result<_, _>>()?;
 
         Ok(SpringRow {
             springs,
@@ -126,22 +139,90 @@ impl FromStr for SpringRow {
     }
 }
 
+// Back in the puzzle's `???.### 1,1,3` format
+impl Display for SpringRow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for spring in self.springs.iter() {
+            let c = match spring {
+                Operational => '.',
+                Damaged => '#',
+                Unknown => '?',
+            };
+            write!(f, "{}", c)?;
+        }
+        write!(f, " {}", self.damaged_runs.iter().join(","))
+    }
+}
+
+/// The condition of a spring
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub enum Spring {
+    /// `.`
     Operational,
+    /// `#`
     Damaged,
+    /// `?`
     Unknown,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{get_input, Input};
+    use crate::FIXTURES;
+    use aoc_core::{check_oracle, Input, Rng, Shrink};
+
+    // Foolishly brute-force through every possible manifestation of the Unknown springs
+    fn possible_arrangements_brute_force(row: &SpringRow) -> usize {
+        let unknown_idxs = row
+            .springs
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, spring)| (*spring == Unknown).then_some(idx));
+        let mut springs = row.springs.clone();
+
+        unknown_idxs
+            .powerset()
+            .filter(|damaged_spring_idxs| {
+                damaged_spring_idxs
+                    .iter()
+                    .for_each(|idx| springs[*idx] = Damaged);
+                let matches = SpringRow::matches(&springs, &row.damaged_runs);
+                damaged_spring_idxs
+                    .iter()
+                    .for_each(|idx| springs[*idx] = Unknown);
+                matches
+            })
+            .count()
+    }
+
+    // Unknown springs become known, and known ones operational
+    impl Shrink for Spring {
+        fn shrink(&self) -> Vec<Spring> {
+            match self {
+                Unknown => vec![Operational, Damaged],
+                Damaged => vec![Operational],
+                Operational => vec![],
+            }
+        }
+    }
+
+    impl Shrink for SpringRow {
+        fn shrink(&self) -> Vec<SpringRow> {
+            (self.springs.clone(), self.damaged_runs.clone())
+                .shrink()
+                .into_iter()
+                .map(|(springs, damaged_runs)| SpringRow {
+                    springs,
+                    damaged_runs,
+                })
+                .collect()
+        }
+    }
 
     #[test]
     fn test_thing_from_str() {
         assert_eq!(
-            get_input::<SpringRow>(Input::Test1)[0],
+            FIXTURES.get_input::<SpringRow>(Input::Test(1)).unwrap()[0],
             SpringRow {
                 springs: vec![
                     Unknown,
@@ -157,14 +238,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_spring_row_display() {
+        let row = "???.### 1,1,3".parse::<SpringRow>().unwrap();
+        assert_eq!(row.to_string(), "???.### 1,1,3");
+    }
+
+    #[test]
+    fn test_spring_row_from_str_error() {
+        assert_eq!(
+            "???.### 1,x,3".parse::<SpringRow>(),
+            Err(ParseError::new(11, "a damaged spring count"))
+        );
+        assert_eq!(
+            "??!.### 1,1,3".parse::<SpringRow>(),
+            Err(ParseError::new(3, "a spring (., # or ?)"))
+        );
+        assert_eq!(
+            "???.###".parse::<SpringRow>(),
+            Err(ParseError::new(8, "a space, then damaged spring counts"))
+        );
+    }
+
     #[test]
     fn test_spring_row_possible_arrangements() {
         assert_eq!(
-            get_input::<SpringRow>(Input::Test1)
+            FIXTURES
+                .get_input::<SpringRow>(Input::Test(1))
+                .unwrap()
                 .iter()
                 .map(|sr| sr.possible_arrangements())
                 .collect::<Vec<_>>(),
             vec![1, 4, 1, 1, 4, 10],
         );
     }
+
+    #[test]
+    fn test_possible_arrangements_oracle() {
+        let generate = |rng: &mut Rng| SpringRow {
+            springs: (0..rng.range(0..16))
+                .map(|_| *rng.choose(&[Operational, Damaged, Unknown, Unknown]))
+                .collect(),
+            damaged_runs: (0..rng.range(0..5)).map(|_| rng.range(1..5)).collect(),
+        };
+
+        check_oracle(
+            0..500,
+            generate,
+            possible_arrangements_brute_force,
+            SpringRow::possible_arrangements,
+        );
+    }
+
+    #[test]
+    fn test_unfolded_possible_arrangements() {
+        assert_eq!(
+            FIXTURES
+                .get_input::<SpringRow>(Input::Test(1))
+                .unwrap()
+                .iter()
+                .map(|sr| sr.unfold().possible_arrangements())
+                .collect::<Vec<_>>(),
+            vec![1, 16384, 1, 16, 2500, 506250],
+        );
+    }
 }
@@ -0,0 +1,46 @@
+use aoc_core::Rng;
+
+// `size` rows (at least one) of 1 to 15 springs (at least one damaged), with up to 10 of them
+// hidden behind `?`
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![];
+
+    for _ in 0..size.max(1) {
+        let len = rng.range(1..16);
+        let mut springs: Vec<char> = (0..len)
+            .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+            .collect();
+        let damaged = rng.range(0..len);
+        springs[damaged] = '#';
+
+        let runs: Vec<String> = springs
+            .split(|c| *c == '.')
+            .filter(|run| !run.is_empty())
+            .map(|run| run.len().to_string())
+            .collect();
+
+        let mut positions: Vec<usize> = (0..len).collect();
+        rng.shuffle(&mut positions);
+        for pos in positions.into_iter().take(rng.range(0..len.min(10) + 1)) {
+            springs[pos] = '?';
+        }
+
+        lines.push(format!(
+            "{} {}",
+            springs.iter().collect::<String>(),
+            runs.join(",")
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day12;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day12>(0..20, 50);
+    }
+}
@@ -0,0 +1,85 @@
+//! Day 12: Hot Springs
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{parse_lines, trace, Fixtures, ParseError, Rng, Solution};
+pub use data::*;
+use rayon::prelude::*;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Vec<SpringRow>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<SpringRow>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_1(spring_rows: Vec<SpringRow>) -> usize {
+        part_1(spring_rows)
+    }
+
+    fn part_2(spring_rows: Vec<SpringRow>) -> usize {
+        part_2(spring_rows)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// For each row, count all of the different arrangements of operational and broken springs that meet
+/// the given criteria. What is the sum of those counts?
+pub fn part_1(spring_rows: Vec<SpringRow>) -> usize {
+    let sum = spring_rows
+        .par_iter()
+        .map(|spring_row| {
+            let arrangements = spring_row.possible_arrangements();
+            trace!(Steps, "{}: {} arrangements", spring_row, arrangements);
+            arrangements
+        })
+        .sum();
+
+    trace!(
+        Summary,
+        "{} rows have {} arrangements in total",
+        spring_rows.len(),
+        sum
+    );
+    sum
+}
+
+/// Unfold the records: each row's springs become five copies joined by `?`, and its damaged runs five
+/// copies too. What is the new sum of possible arrangement counts?
+pub fn part_2(spring_rows: Vec<SpringRow>) -> usize {
+    let sum = spring_rows
+        .par_iter()
+        .map(|spring_row| {
+            let arrangements = spring_row.unfold().possible_arrangements();
+            trace!(
+                Steps,
+                "{} unfolded: {} arrangements",
+                spring_row,
+                arrangements
+            );
+            arrangements
+        })
+        .sum();
+
+    trace!(
+        Summary,
+        "{} unfolded rows have {} arrangements in total",
+        spring_rows.len(),
+        sum
+    );
+    sum
+}
@@ -1,13 +1,21 @@
+use aoc_core::{Frame, Grid, Highlight, ParseError, Pos};
+
+/// A grid of `#` rocks and `.` ash
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub struct Pattern {
-    rows: Vec<String>,
+    grid: Grid<char>,
 }
 
 impl Pattern {
-    pub fn build(rows: Vec<String>) -> Pattern {
-        Pattern { rows }
+    /// Parses one pattern's rows
+    pub fn build(rows: &[String]) -> Result<Pattern, ParseError> {
+        let grid = Grid::parse(rows, "`#` or `.`", |char| {
+            matches!(char, '#' | '.').then_some(char)
+        })?;
+        Ok(Pattern { grid })
     }
 
+    /// This pattern with each cell flipped in turn, row by row
     pub fn unsmudged_patterns(&self) -> UnsmudgedPatternsIterator {
         UnsmudgedPatternsIterator {
             pattern: self.clone(),
@@ -15,29 +23,24 @@ impl Pattern {
         }
     }
 
+    /// Turns rock to ash or ash to rock
     pub fn flip(&mut self, row_idx: usize, col_idx: usize) {
-        let row = self.rows.get_mut(row_idx).unwrap();
-        let char = row.chars().nth(col_idx).unwrap();
-        let toggled_char = match char {
-            '#' => ".",
-            '.' => "#",
-            _ => panic!(),
+        let cell = &mut self.grid[Pos::new(row_idx, col_idx)];
+        *cell = match cell {
+            '#' => '.',
+            _ => '#',
         };
-        row.replace_range(col_idx..=col_idx, toggled_char);
     }
 
-    pub fn rotated_90_deg(&self) -> Pattern {
-        let mut rows = vec![String::new(); self.rows[0].len()];
-
-        for row in self.rows.iter().rev() {
-            for (new_row_idx, char) in row.chars().enumerate() {
-                rows[new_row_idx].push(char);
-            }
+    /// Columns become rows, so a vertical mirror becomes a horizontal one
+    pub fn transposed(&self) -> Pattern {
+        Pattern {
+            grid: self.grid.transposed(),
         }
-
-        Pattern { rows }
     }
 
+    /// 100 times the rows above the reflection line, or the columns left of it. Horizontal lines
+    /// win if there are both.
     pub fn summary(&self) -> Option<usize> {
         if let Some(above) = self.rows_above_mirror().first() {
             Some(above * 100)
@@ -46,7 +49,7 @@ impl Pattern {
         }
     }
 
-    // Returns the summary that is NOT the given value
+    /// Returns the summary that is NOT the given value
     pub fn summary_different_than(&self, not_summary: usize) -> Option<usize> {
         let above = self.rows_above_mirror().iter().map(|c| c * 100).collect();
         let left = self.rows_left_of_mirror();
@@ -54,22 +57,28 @@ impl Pattern {
         summaries.into_iter().find(|s| *s != not_summary)
     }
 
+    /// For each horizontal reflection line, how many rows are above it
     pub fn rows_above_mirror(&self) -> Vec<usize> {
         let mut solutions = vec![];
 
-        for row_count in 1..=(self.rows.len() - 1) {
-            let (take_top, skip_top) = if row_count * 2 <= self.rows.len() {
+        let row_len = self.grid.rows();
+        for row_count in 1..=(row_len - 1) {
+            let (take_top, skip_top) = if row_count * 2 <= row_len {
                 (row_count, 0)
             } else {
                 // Imagine there are 100 rows, and row_count=98. You can actually only compare against rows 98/99 on the
                 // bottom, so the top needs to be rows 96/97, hence take=2 and skip=96.
-                let take = self.rows.len() - row_count;
+                let take = row_len - row_count;
                 let skip = row_count - take;
                 (take, skip)
             };
 
-            let rows_above = self.rows.iter().skip(skip_top).take(take_top);
-            let rows_below = self.rows.iter().skip(skip_top + take_top).take(row_count);
+            let rows_above = self.grid.iter_rows().skip(skip_top).take(take_top);
+            let rows_below = self
+                .grid
+                .iter_rows()
+                .skip(skip_top + take_top)
+                .take(row_count);
 
             let mirrored = rows_above.eq(rows_below.rev());
             if mirrored {
@@ -80,16 +89,59 @@ impl Pattern {
         solutions
     }
 
+    /// For each vertical reflection line, how many columns are left of it
     pub fn rows_left_of_mirror(&self) -> Vec<usize> {
-        self.rotated_90_deg().rows_above_mirror()
+        self.transposed().rows_above_mirror()
+    }
+
+    /// The smudge whose fix makes a different reflection line valid, and the new summary. Slower
+    /// than walking `unsmudged_patterns`, but simpler for one-off uses like rendering.
+    pub fn find_smudge(&self) -> Option<(Pos, usize)> {
+        let orig_summary = self.summary()?;
+
+        self.grid.positions().find_map(|pos| {
+            let mut pattern = self.clone();
+            pattern.flip(pos.row, pos.col);
+            pattern
+                .summary_different_than(orig_summary)
+                .map(|summary| (pos, summary))
+        })
+    }
+
+    /// Highlights the rows (or columns) either side of the reflection line described by `summary`
+    pub fn frame(&self, summary: Option<usize>, smudge: Option<Pos>) -> Frame {
+        let mut frame = Frame::new(self.grid.clone());
+
+        for pos in self.grid.positions() {
+            let beside_mirror = match summary {
+                Some(summary) if summary >= 100 => {
+                    pos.row + 1 == summary / 100 || pos.row == summary / 100
+                }
+                Some(summary) => pos.col + 1 == summary || pos.col == summary,
+                None => false,
+            };
+            if beside_mirror {
+                frame.highlight(pos, Highlight::Blue);
+            }
+        }
+        if let Some(smudge) = smudge {
+            frame.highlight(smudge, Highlight::Red);
+        }
+
+        frame
     }
 }
 
+/// An iterator whose items borrow from the iterator itself, so it can hand out the same
+/// pattern over and over
 pub trait StreamingIterator<'a> {
+    /// What each call to `next` returns
     type Item;
+    /// The next item, or `None` when there are no more
     fn next(&'a mut self) -> Option<Self::Item>;
 }
 
+/// See `Pattern::unsmudged_patterns`
 pub struct UnsmudgedPatternsIterator {
     pattern: Pattern,
     iteration: usize,
@@ -99,16 +151,12 @@ impl<'a> StreamingIterator<'a> for UnsmudgedPatternsIterator {
     type Item = &'a Pattern;
 
     fn next(&'a mut self) -> Option<Self::Item> {
-        let row_col_idx = |it| {
-            (
-                it / self.pattern.rows[0].len(),
-                it % self.pattern.rows[0].len(),
-            )
-        };
+        let cols = self.pattern.grid.cols();
+        let row_col_idx = |it| (it / cols, it % cols);
 
         // Break once all Patterns are exhausted
         let (row, col) = row_col_idx(self.iteration);
-        if self.pattern.rows.len() == row {
+        if self.pattern.grid.rows() == row {
             return None;
         }
 
@@ -0,0 +1,161 @@
+use crate::data::Pattern;
+use aoc_core::Rng;
+
+// A line of reflection: below row N (horizontal) or right of column N (vertical)
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+enum Mirror {
+    Horizontal(usize),
+    Vertical(usize),
+}
+
+impl Mirror {
+    fn random(rng: &mut Rng, rows: usize, cols: usize) -> Mirror {
+        if rng.chance(0.5) {
+            Mirror::Horizontal(rng.range(1..rows))
+        } else {
+            Mirror::Vertical(rng.range(1..cols))
+        }
+    }
+
+    // Where a cell lands when reflected, if that's still inside the pattern
+    fn reflect(
+        &self,
+        (row, col): (usize, usize),
+        rows: usize,
+        cols: usize,
+    ) -> Option<(usize, usize)> {
+        match *self {
+            Mirror::Horizontal(n) => (2 * n - 1)
+                .checked_sub(row)
+                .filter(|row| *row < rows)
+                .map(|row| (row, col)),
+            Mirror::Vertical(n) => (2 * n - 1)
+                .checked_sub(col)
+                .filter(|col| *col < cols)
+                .map(|col| (row, col)),
+        }
+    }
+
+    fn summary(&self) -> usize {
+        match *self {
+            Mirror::Horizontal(n) => n * 100,
+            Mirror::Vertical(n) => n,
+        }
+    }
+}
+
+// `size` patterns of 5 to 15 rows and columns. Each reflects across exactly one line as is, and
+// across a different one once its smudge is fixed.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size)
+        .map(|_| loop {
+            if let Some(pattern) = try_pattern(rng) {
+                break pattern;
+            }
+        })
+        .collect();
+
+    patterns.join("\n\n")
+}
+
+// Picks the two mirrors and the smudge, then solves for cells that satisfy both: each cell must
+// match its reflection across the first mirror, and across the second too apart from the smudge.
+// That's a set of "these two cells are equal/different" constraints, solved with a union-find that
+// tracks parity. Some picks can't be satisfied, or reflect more than intended, so callers retry.
+fn try_pattern(rng: &mut Rng) -> Option<String> {
+    let (rows, cols) = (rng.range(5..16), rng.range(5..16));
+    let original = Mirror::random(rng, rows, cols);
+    let unsmudged = Mirror::random(rng, rows, cols);
+    if original == unsmudged {
+        return None;
+    }
+
+    let mirrored: Vec<(usize, usize)> = (0..rows)
+        .flat_map(|row| (0..cols).map(move |col| (row, col)))
+        .filter(|cell| unsmudged.reflect(*cell, rows, cols).is_some())
+        .collect();
+    let smudge = *rng.choose(&mirrored);
+
+    let idx = |(row, col): (usize, usize)| row * cols + col;
+    let mut cells = ParityUnionFind::new(rows * cols);
+    for cell in (0..rows).flat_map(|row| (0..cols).map(move |col| (row, col))) {
+        if let Some(reflected) = original.reflect(cell, rows, cols) {
+            cells.union(idx(cell), idx(reflected), false)?;
+        }
+        if let Some(reflected) = unsmudged.reflect(cell, rows, cols) {
+            let differ = (cell == smudge) != (reflected == smudge);
+            cells.union(idx(cell), idx(reflected), differ)?;
+        }
+    }
+
+    let root_values: Vec<bool> = (0..rows * cols).map(|_| rng.chance(0.5)).collect();
+    let lines: Vec<String> = (0..rows)
+        .map(|row| {
+            (0..cols)
+                .map(|col| {
+                    let (root, parity) = cells.find(idx((row, col)));
+                    if root_values[root] != parity {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let pattern = Pattern::build(&lines).ok()?;
+    let mirrors = pattern.rows_above_mirror().len() + pattern.rows_left_of_mirror().len();
+    let smudge_fix = pattern.find_smudge().map(|(_, summary)| summary);
+    (mirrors == 1 && smudge_fix == Some(unsmudged.summary())).then(|| lines.join("\n"))
+}
+
+struct ParityUnionFind {
+    parent: Vec<usize>,
+    // Whether each element differs from its parent
+    parity: Vec<bool>,
+}
+
+impl ParityUnionFind {
+    fn new(len: usize) -> ParityUnionFind {
+        ParityUnionFind {
+            parent: (0..len).collect(),
+            parity: vec![false; len],
+        }
+    }
+
+    // The root, and whether `x` differs from it
+    fn find(&mut self, x: usize) -> (usize, bool) {
+        if self.parent[x] == x {
+            return (x, false);
+        }
+
+        let (root, parent_parity) = self.find(self.parent[x]);
+        self.parent[x] = root;
+        self.parity[x] ^= parent_parity;
+        (root, self.parity[x])
+    }
+
+    // `None` if this contradicts an earlier constraint
+    fn union(&mut self, a: usize, b: usize, differ: bool) -> Option<()> {
+        let (root_a, parity_a) = self.find(a);
+        let (root_b, parity_b) = self.find(b);
+
+        if root_a == root_b {
+            return (parity_a ^ parity_b == differ).then_some(());
+        }
+        self.parent[root_a] = root_b;
+        self.parity[root_a] = parity_a ^ parity_b ^ differ;
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day13;
+
+    #[test]
+    fn test_generate() {
+        aoc_core::check_generator::<Day13>(0..20, 20);
+    }
+}
@@ -0,0 +1,175 @@
+//! Day 13: Point of Incidence
+#![warn(missing_docs)]
+
+mod data;
+mod generator;
+
+use aoc_core::{parse_whole, trace, Fixtures, Frame, ParseError, Part, Rng, Solution};
+pub use data::*;
+use rayon::prelude::*;
+
+aoc_core::fixtures!();
+
+/// The puzzle's `aoc_core::Solution`, as registered with the runner
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const FIXTURES: &'static Fixtures = &FIXTURES;
+
+    type Parsed = Vec<Pattern>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+        parse_whole(input, lines_to_vec_of_patterns)
+    }
+
+    fn part_1(patterns: Vec<Pattern>) -> usize {
+        part_1(patterns)
+    }
+
+    fn part_2(patterns: Vec<Pattern>) -> usize {
+        part_2(patterns)
+    }
+
+    fn render(patterns: Vec<Pattern>, part: Part) -> Option<Frame> {
+        Some(render(patterns, part))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generator::generate(rng, size))
+    }
+}
+
+/// Add up the number of columns to the left of each vertical line of reflection, plus 100 multiplied by the number of
+/// rows above each horizontal line of reflection.
+pub fn part_1(patterns: Vec<Pattern>) -> usize {
+    let sum = patterns
+        .par_iter()
+        .enumerate()
+        .map(|(idx, p)| {
+            let summary = p.summary().unwrap();
+            trace!(Steps, "Pattern {}: {}", idx + 1, describe_summary(summary));
+            summary
+        })
+        .sum();
+
+    trace!(
+        Summary,
+        "The summaries of {} patterns add up to {}",
+        patterns.len(),
+        sum
+    );
+    sum
+}
+
+/// In each pattern, fix the smudge that causes a different reflection line to be valid. What is the new summary?
+pub fn part_2(patterns: Vec<Pattern>) -> usize {
+    let sum = patterns
+        .par_iter()
+        .enumerate()
+        .map(|(idx, pattern)| {
+            let orig_summary = pattern.summary().unwrap();
+            let mut unsmudged_patterns = pattern.unsmudged_patterns();
+
+            while let Some(pattern) = unsmudged_patterns.next() {
+                if let Some(summary) = pattern.summary_different_than(orig_summary) {
+                    trace!(
+                        Steps,
+                        "Pattern {}: {}, where it was {}",
+                        idx + 1,
+                        describe_summary(summary),
+                        describe_summary(orig_summary)
+                    );
+                    return summary;
+                }
+            }
+
+            panic!(
+                "Pattern {:?} never had a different reflection line for all its smudges",
+                pattern
+            );
+        })
+        .sum();
+
+    trace!(
+        Summary,
+        "The unsmudged summaries of {} patterns add up to {}",
+        patterns.len(),
+        sum
+    );
+    sum
+}
+
+fn describe_summary(summary: usize) -> String {
+    if summary >= 100 {
+        format!("a horizontal mirror below row {}", summary / 100)
+    } else {
+        format!("a vertical mirror right of column {}", summary)
+    }
+}
+
+// Every pattern with its reflection line, and for part 2 the smudge that moved it
+fn render(patterns: Vec<Pattern>, part: Part) -> Frame {
+    let frames: Vec<Frame> = patterns
+        .iter()
+        .map(|pattern| match part {
+            Part::One => pattern.frame(pattern.summary(), None),
+            Part::Two => match pattern.find_smudge() {
+                Some((smudge, summary)) => pattern.frame(Some(summary), Some(smudge)),
+                None => pattern.frame(None, None),
+            },
+        })
+        .collect();
+
+    Frame::stacked(&frames)
+}
+
+fn lines_to_vec_of_patterns(lines: Vec<String>) -> Result<Vec<Pattern>, ParseError> {
+    let mut patterns = vec![];
+    let mut pattern_rows = vec![];
+
+    let mut lines_iter = lines.into_iter().enumerate().peekable();
+    while let Some((idx, line)) = lines_iter.next() {
+        if line.is_empty() {
+            continue;
+        }
+
+        pattern_rows.push(line);
+
+        if lines_iter.peek().is_none_or(|(_, line)| line.is_empty()) {
+            // Errors are numbered from the pattern's first line, not the input's
+            let first_line = idx + 1 - pattern_rows.len();
+            let pattern = Pattern::build(&pattern_rows).map_err(|mut err| {
+                err.line = err.line.map(|line| line + first_line);
+                err
+            })?;
+            patterns.push(pattern);
+            pattern_rows = vec![];
+        }
+    }
+
+    Ok(patterns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_to_vec_of_patterns_error() {
+        let lines = |text: &str| text.split('\n').map(String::from).collect();
+        assert_eq!(
+            lines_to_vec_of_patterns(lines("#.\n.#\n\n##\n#x")),
+            Err(ParseError::new(2, "`#` or `.`").with_line(5, "#x"))
+        );
+    }
+
+    #[test]
+    fn test_parse_error_after_blank_lines() {
+        assert_eq!(
+            Day13::parse("\n\n#.\n.#\n\n##\n#x\n").err(),
+            Some(ParseError::new(2, "`#` or `.`").with_line(7, "#x"))
+        );
+    }
+}